@@ -0,0 +1,509 @@
+//! CSS color parsing module
+//!
+//! This module parses named colors, hex colors and color functions into `Color` values,
+//! and converts every supported color space to sRGB for painting.
+
+use super::enums::{Color, Token};
+use super::parser::{serialize_tokens, split_top_level, trim_whitespace};
+
+type ParseResult<T> = Result<T, String>;
+
+// CSS Color Level 4 adlandırılmış renkleri (alfabetik sırada)
+const NAMED_COLORS: [(&str, (u8, u8, u8)); 148] = [
+    ("aliceblue", (240, 248, 255)),
+    ("antiquewhite", (250, 235, 215)),
+    ("aqua", (0, 255, 255)),
+    ("aquamarine", (127, 255, 212)),
+    ("azure", (240, 255, 255)),
+    ("beige", (245, 245, 220)),
+    ("bisque", (255, 228, 196)),
+    ("black", (0, 0, 0)),
+    ("blanchedalmond", (255, 235, 205)),
+    ("blue", (0, 0, 255)),
+    ("blueviolet", (138, 43, 226)),
+    ("brown", (165, 42, 42)),
+    ("burlywood", (222, 184, 135)),
+    ("cadetblue", (95, 158, 160)),
+    ("chartreuse", (127, 255, 0)),
+    ("chocolate", (210, 105, 30)),
+    ("coral", (255, 127, 80)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("cornsilk", (255, 248, 220)),
+    ("crimson", (220, 20, 60)),
+    ("cyan", (0, 255, 255)),
+    ("darkblue", (0, 0, 139)),
+    ("darkcyan", (0, 139, 139)),
+    ("darkgoldenrod", (184, 134, 11)),
+    ("darkgray", (169, 169, 169)),
+    ("darkgreen", (0, 100, 0)),
+    ("darkgrey", (169, 169, 169)),
+    ("darkkhaki", (189, 183, 107)),
+    ("darkmagenta", (139, 0, 139)),
+    ("darkolivegreen", (85, 107, 47)),
+    ("darkorange", (255, 140, 0)),
+    ("darkorchid", (153, 50, 204)),
+    ("darkred", (139, 0, 0)),
+    ("darksalmon", (233, 150, 122)),
+    ("darkseagreen", (143, 188, 143)),
+    ("darkslateblue", (72, 61, 139)),
+    ("darkslategray", (47, 79, 79)),
+    ("darkslategrey", (47, 79, 79)),
+    ("darkturquoise", (0, 206, 209)),
+    ("darkviolet", (148, 0, 211)),
+    ("deeppink", (255, 20, 147)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dimgray", (105, 105, 105)),
+    ("dimgrey", (105, 105, 105)),
+    ("dodgerblue", (30, 144, 255)),
+    ("firebrick", (178, 34, 34)),
+    ("floralwhite", (255, 250, 240)),
+    ("forestgreen", (34, 139, 34)),
+    ("fuchsia", (255, 0, 255)),
+    ("gainsboro", (220, 220, 220)),
+    ("ghostwhite", (248, 248, 255)),
+    ("gold", (255, 215, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gray", (128, 128, 128)),
+    ("green", (0, 128, 0)),
+    ("greenyellow", (173, 255, 47)),
+    ("grey", (128, 128, 128)),
+    ("honeydew", (240, 255, 240)),
+    ("hotpink", (255, 105, 180)),
+    ("indianred", (205, 92, 92)),
+    ("indigo", (75, 0, 130)),
+    ("ivory", (255, 255, 240)),
+    ("khaki", (240, 230, 140)),
+    ("lavender", (230, 230, 250)),
+    ("lavenderblush", (255, 240, 245)),
+    ("lawngreen", (124, 252, 0)),
+    ("lemonchiffon", (255, 250, 205)),
+    ("lightblue", (173, 216, 230)),
+    ("lightcoral", (240, 128, 128)),
+    ("lightcyan", (224, 255, 255)),
+    ("lightgoldenrodyellow", (250, 250, 210)),
+    ("lightgray", (211, 211, 211)),
+    ("lightgreen", (144, 238, 144)),
+    ("lightgrey", (211, 211, 211)),
+    ("lightpink", (255, 182, 193)),
+    ("lightsalmon", (255, 160, 122)),
+    ("lightseagreen", (32, 178, 170)),
+    ("lightskyblue", (135, 206, 250)),
+    ("lightslategray", (119, 136, 153)),
+    ("lightslategrey", (119, 136, 153)),
+    ("lightsteelblue", (176, 196, 222)),
+    ("lightyellow", (255, 255, 224)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("linen", (250, 240, 230)),
+    ("magenta", (255, 0, 255)),
+    ("maroon", (128, 0, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("mediumblue", (0, 0, 205)),
+    ("mediumorchid", (186, 85, 211)),
+    ("mediumpurple", (147, 112, 219)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("mediumslateblue", (123, 104, 238)),
+    ("mediumspringgreen", (0, 250, 154)),
+    ("mediumturquoise", (72, 209, 204)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("midnightblue", (25, 25, 112)),
+    ("mintcream", (245, 255, 250)),
+    ("mistyrose", (255, 228, 225)),
+    ("moccasin", (255, 228, 181)),
+    ("navajowhite", (255, 222, 173)),
+    ("navy", (0, 0, 128)),
+    ("oldlace", (253, 245, 230)),
+    ("olive", (128, 128, 0)),
+    ("olivedrab", (107, 142, 35)),
+    ("orange", (255, 165, 0)),
+    ("orangered", (255, 69, 0)),
+    ("orchid", (218, 112, 214)),
+    ("palegoldenrod", (238, 232, 170)),
+    ("palegreen", (152, 251, 152)),
+    ("paleturquoise", (175, 238, 238)),
+    ("palevioletred", (219, 112, 147)),
+    ("papayawhip", (255, 239, 213)),
+    ("peachpuff", (255, 218, 185)),
+    ("peru", (205, 133, 63)),
+    ("pink", (255, 192, 203)),
+    ("plum", (221, 160, 221)),
+    ("powderblue", (176, 224, 230)),
+    ("purple", (128, 0, 128)),
+    ("rebeccapurple", (102, 51, 153)),
+    ("red", (255, 0, 0)),
+    ("rosybrown", (188, 143, 143)),
+    ("royalblue", (65, 105, 225)),
+    ("saddlebrown", (139, 69, 19)),
+    ("salmon", (250, 128, 114)),
+    ("sandybrown", (244, 164, 96)),
+    ("seagreen", (46, 139, 87)),
+    ("seashell", (255, 245, 238)),
+    ("sienna", (160, 82, 45)),
+    ("silver", (192, 192, 192)),
+    ("skyblue", (135, 206, 235)),
+    ("slateblue", (106, 90, 205)),
+    ("slategray", (112, 128, 144)),
+    ("slategrey", (112, 128, 144)),
+    ("snow", (255, 250, 250)),
+    ("springgreen", (0, 255, 127)),
+    ("steelblue", (70, 130, 180)),
+    ("tan", (210, 180, 140)),
+    ("teal", (0, 128, 128)),
+    ("thistle", (216, 191, 216)),
+    ("tomato", (255, 99, 71)),
+    ("turquoise", (64, 224, 208)),
+    ("violet", (238, 130, 238)),
+    ("wheat", (245, 222, 179)),
+    ("white", (255, 255, 255)),
+    ("whitesmoke", (245, 245, 245)),
+    ("yellow", (255, 255, 0)),
+    ("yellowgreen", (154, 205, 50)),
+];
+
+/// Looks up a CSS named color (case-insensitive), including `transparent`
+pub fn named_color(name: &str) -> Option<Color> {
+    let name = name.to_lowercase();
+    if name == "transparent" {
+        return Some(Color::RGBA(0, 0, 0, 0.0));
+    }
+    NAMED_COLORS.binary_search_by(|(candidate, _)| candidate.cmp(&name.as_str()))
+        .ok()
+        .map(|index| {
+            let (r, g, b) = NAMED_COLORS[index].1;
+            Color::RGBA(r, g, b, 1.0)
+        })
+}
+
+/// Parses the digits of a `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` color
+pub fn hex_color(digits: &str) -> ParseResult<Color> {
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid hex color '#{}'", digits));
+    }
+    // Kısa biçimde her hane iki kez yazılmış sayılır (örn: #f80 = #ff8800)
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits.chars()
+            .map(|c| u8::from_str_radix(&c.to_string().repeat(2), 16).unwrap())
+            .collect(),
+        6 | 8 => (0..digits.len()).step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+            .collect(),
+        _ => return Err(format!("Invalid hex color '#{}'", digits)),
+    };
+    let alpha = channels.get(3).map_or(1.0, |&a| a as f32 / 255.0);
+    Ok(Color::RGBA(channels[0], channels[1], channels[2], alpha))
+}
+
+/// Returns true if the function name is a supported color function
+pub fn is_color_function(name: &str) -> bool {
+    matches!(name.to_lowercase().as_str(), "rgb" | "rgba" | "hsl" | "hsla" | "hwb" | "lab" | "lch" | "oklch")
+}
+
+// Renk fonksiyonu bileşeni; `none` anahtar kelimesi sıfır sayılır
+#[derive(Debug, Clone, Copy)]
+enum Component {
+    Number(f32),
+    Percentage(f32),
+    Angle(f32), // Derece cinsinden
+}
+
+fn component(tokens: &[Token]) -> Option<Component> {
+    match trim_whitespace(tokens) {
+        [Token::Number(value)] => Some(Component::Number(*value)),
+        [Token::Percentage(value)] => Some(Component::Percentage(*value)),
+        [Token::Dimension(value, unit)] => {
+            let degrees = match unit.to_lowercase().as_str() {
+                "deg" => *value,
+                "rad" => value.to_degrees(),
+                "grad" => value * 0.9,
+                "turn" => value * 360.0,
+                _ => return None,
+            };
+            Some(Component::Angle(degrees))
+        }
+        [Token::Ident(keyword)] if keyword.eq_ignore_ascii_case("none") => Some(Component::Number(0.0)),
+        _ => None,
+    }
+}
+
+// Fonksiyon argümanlarını bileşenlere ve isteğe bağlı alfa değerine ayırır.
+// Eski sözdizimi virgülle (`rgb(255, 0, 0, 0.5)`), yeni sözdizimi boşluk ve `/` ile (`rgb(255 0 0 / 50%)`) ayrılır.
+fn color_arguments(args: &[Token]) -> Option<(Vec<Component>, Option<Component>)> {
+    let args = trim_whitespace(args);
+    let comma_separated = split_top_level(args, &Token::Comma);
+    if comma_separated.len() > 1 {
+        let mut values = comma_separated.into_iter().map(component).collect::<Option<Vec<_>>>()?;
+        return match values.len() {
+            3 => Some((values, None)),
+            4 => {
+                let alpha = values.pop();
+                Some((values, alpha))
+            }
+            _ => None,
+        };
+    }
+
+    let mut parts = split_top_level(args, &Token::Delim('/')).into_iter();
+    let channels = parts.next()?;
+    let alpha = match parts.next() {
+        Some(alpha) => Some(component(alpha)?),
+        None => None,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    let values = split_top_level(channels, &Token::Whitespace).into_iter()
+        .filter(|part| !part.is_empty())
+        .map(component)
+        .collect::<Option<Vec<_>>>()?;
+    (values.len() == 3).then_some((values, alpha))
+}
+
+// Alfa değeri 0-1 aralığında bir sayı ya da yüzde olabilir
+fn alpha_value(alpha: Option<Component>) -> Option<f32> {
+    match alpha {
+        None => Some(1.0),
+        Some(Component::Number(value)) => Some(value.clamp(0.0, 1.0)),
+        Some(Component::Percentage(value)) => Some((value / 100.0).clamp(0.0, 1.0)),
+        Some(Component::Angle(_)) => None,
+    }
+}
+
+// Ton açısı: birimsiz sayılar derece sayılır, sonuç 0-360 aralığına indirgenir
+fn hue(value: Component) -> Option<f32> {
+    match value {
+        Component::Number(degrees) | Component::Angle(degrees) => Some(degrees.rem_euclid(360.0)),
+        Component::Percentage(_) => None,
+    }
+}
+
+// Sayı ya da yüzde; yüzdeler `percent_reference` değerinin yüzdesi olarak çözülür
+fn number_or_percentage(value: Component, percent_reference: f32) -> Option<f32> {
+    match value {
+        Component::Number(number) => Some(number),
+        Component::Percentage(percentage) => Some(percentage / 100.0 * percent_reference),
+        Component::Angle(_) => None,
+    }
+}
+
+/// Parses the arguments of a color function such as `rgb()` or `hsl()`
+pub fn parse_color_function(name: &str, args: &[Token]) -> ParseResult<Color> {
+    let invalid = || format!("Invalid {} value: {}({})", name, name, serialize_tokens(args));
+    let (values, alpha) = color_arguments(args).ok_or_else(invalid)?;
+    let alpha = alpha_value(alpha).ok_or_else(invalid)?;
+    let color = match name.to_lowercase().as_str() {
+        "rgb" | "rgba" => {
+            // Kanallar 0-255 arası sayı ya da yüzde olabilir
+            let channel = |value: Component| number_or_percentage(value, 255.0)
+                .map(|value| value.round().clamp(0.0, 255.0) as u8);
+            channel(values[0]).zip(channel(values[1])).zip(channel(values[2]))
+                .map(|((r, g), b)| Color::RGBA(r, g, b, alpha))
+        }
+        "hsl" | "hsla" => {
+            let percent = |value: Component| number_or_percentage(value, 100.0).map(|value| value.clamp(0.0, 100.0));
+            hue(values[0]).zip(percent(values[1])).zip(percent(values[2]))
+                .map(|((h, s), l)| Color::HSLA(h, s, l, alpha))
+        }
+        "hwb" => {
+            let percent = |value: Component| number_or_percentage(value, 100.0).map(|value| value.clamp(0.0, 100.0));
+            hue(values[0]).zip(percent(values[1])).zip(percent(values[2]))
+                .map(|((h, w), b)| Color::HWBA(h, w, b, alpha))
+        }
+        // lab(): L 0-100, a/b için %100 = 125
+        "lab" => number_or_percentage(values[0], 100.0)
+            .zip(number_or_percentage(values[1], 125.0))
+            .zip(number_or_percentage(values[2], 125.0))
+            .map(|((l, a), b)| Color::LAB(l.clamp(0.0, 100.0), a, b, alpha)),
+        // lch(): L 0-100, C için %100 = 150
+        "lch" => number_or_percentage(values[0], 100.0)
+            .zip(number_or_percentage(values[1], 150.0))
+            .zip(hue(values[2]))
+            .map(|((l, c), h)| Color::LCH(l.clamp(0.0, 100.0), c.max(0.0), h, alpha)),
+        // oklch(): L 0-1, C için %100 = 0.4
+        _ => number_or_percentage(values[0], 1.0)
+            .zip(number_or_percentage(values[1], 0.4))
+            .zip(hue(values[2]))
+            .map(|((l, c), h)| Color::OKLCH(l.clamp(0.0, 1.0), c.max(0.0), h, alpha)),
+    };
+    color.ok_or_else(invalid)
+}
+
+impl Color {
+    /// Converts the color to 8-bit sRGB channels and an alpha value between 0 and 1
+    pub fn to_srgb(&self) -> (u8, u8, u8, f32) {
+        let (r, g, b, alpha) = match *self {
+            Color::RGBA(r, g, b, alpha) => return (r, g, b, alpha),
+            Color::HSLA(h, s, l, alpha) => {
+                let (r, g, b) = hsl_to_rgb(h, s / 100.0, l / 100.0);
+                (r, g, b, alpha)
+            }
+            Color::HWBA(h, w, b, alpha) => {
+                let (r, g, b) = hwb_to_rgb(h, w / 100.0, b / 100.0);
+                (r, g, b, alpha)
+            }
+            Color::LAB(l, a, b, alpha) => {
+                let (r, g, b) = lab_to_rgb(l, a, b);
+                (r, g, b, alpha)
+            }
+            Color::LCH(l, c, h, alpha) => {
+                let (a, b) = polar_to_cartesian(c, h);
+                let (r, g, b) = lab_to_rgb(l, a, b);
+                (r, g, b, alpha)
+            }
+            Color::OKLCH(l, c, h, alpha) => {
+                let (a, b) = polar_to_cartesian(c, h);
+                let (r, g, b) = oklab_to_rgb(l, a, b);
+                (r, g, b, alpha)
+            }
+        };
+        // Gamut dışındaki renkler sRGB aralığına kırpılır
+        let channel = |value: f32| (value * 255.0).round().clamp(0.0, 255.0) as u8;
+        (channel(r), channel(g), channel(b), alpha)
+    }
+}
+
+// Kutupsal (kroma, ton) bileşenleri kartezyen (a, b) bileşenlerine çevirir
+fn polar_to_cartesian(chroma: f32, hue: f32) -> (f32, f32) {
+    let radians = hue.to_radians();
+    (chroma * radians.cos(), chroma * radians.sin())
+}
+
+// CSS Color 4'teki hsl -> srgb dönüşümü; s ve l 0-1 aralığında
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (f32, f32, f32) {
+    let f = |n: f32| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    (f(0.0), f(8.0), f(4.0))
+}
+
+fn hwb_to_rgb(hue: f32, white: f32, black: f32) -> (f32, f32, f32) {
+    // Beyaz ve siyahın toplamı 1'i aşarsa renk gridir
+    if white + black >= 1.0 {
+        let gray = white / (white + black);
+        return (gray, gray, gray);
+    }
+    let (r, g, b) = hsl_to_rgb(hue, 1.0, 0.5);
+    let scale = |channel: f32| channel * (1.0 - white - black) + white;
+    (scale(r), scale(g), scale(b))
+}
+
+// Doğrusal ışık değerini sRGB gama eğrisiyle kodlar
+fn gamma_encode(linear: f32) -> f32 {
+    let sign = linear.signum();
+    let linear = linear.abs();
+    if linear > 0.0031308 {
+        sign * (1.055 * linear.powf(1.0 / 2.4) - 0.055)
+    } else {
+        sign * 12.92 * linear
+    }
+}
+
+// CIE Lab (D50) -> XYZ (D50) -> XYZ (D65, Bradford) -> sRGB
+fn lab_to_rgb(lightness: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    const KAPPA: f32 = 24389.0 / 27.0;
+    const EPSILON: f32 = 216.0 / 24389.0;
+    const WHITE_D50: [f32; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+    let f1 = (lightness + 16.0) / 116.0;
+    let f0 = a / 500.0 + f1;
+    let f2 = f1 - b / 200.0;
+    let x = if f0.powi(3) > EPSILON { f0.powi(3) } else { (116.0 * f0 - 16.0) / KAPPA };
+    let y = if lightness > KAPPA * EPSILON { f1.powi(3) } else { lightness / KAPPA };
+    let z = if f2.powi(3) > EPSILON { f2.powi(3) } else { (116.0 * f2 - 16.0) / KAPPA };
+    let (x, y, z) = (x * WHITE_D50[0], y * WHITE_D50[1], z * WHITE_D50[2]);
+
+    // Bradford kromatik uyarlaması: D50 -> D65
+    let x65 = 0.955_473_4 * x - 0.023_098_538 * y + 0.063_259_31 * z;
+    let y65 = -0.028_369_706 * x + 1.009_995_5 * y + 0.021_041_399 * z;
+    let z65 = 0.012_314_002 * x - 0.020_507_697 * y + 1.330_365_9 * z;
+
+    let r = 3.240_97 * x65 - 1.537_383_2 * y65 - 0.498_610_76 * z65;
+    let g = -0.969_243_6 * x65 + 1.875_967_5 * y65 + 0.041_555_06 * z65;
+    let b = 0.055_630_08 * x65 - 0.203_976_96 * y65 + 1.056_971_5 * z65;
+    (gamma_encode(r), gamma_encode(g), gamma_encode(b))
+}
+
+// OKLab -> LMS -> doğrusal sRGB
+fn oklab_to_rgb(lightness: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+    let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+    let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+    (gamma_encode(r), gamma_encode(g), gamma_encode(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::tokenizer::Tokenizer;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        Tokenizer::new(input).tokenize().into_iter().map(|(token, _)| token).collect()
+    }
+
+    // Renk fonksiyonunu ayrıştırıp sRGB kanallarına çevirir
+    fn srgb(name: &str, args: &str) -> (u8, u8, u8, f32) {
+        parse_color_function(name, &tokens(args)).unwrap().to_srgb()
+    }
+
+    #[test]
+    fn named_colors_are_case_insensitive() {
+        assert_eq!(named_color("RebeccaPurple").unwrap().to_srgb(), (102, 51, 153, 1.0));
+        assert_eq!(named_color("yellowgreen").unwrap().to_srgb(), (154, 205, 50, 1.0));
+        assert_eq!(named_color("Transparent").unwrap().to_srgb(), (0, 0, 0, 0.0));
+        assert!(named_color("notacolor").is_none());
+    }
+
+    #[test]
+    fn hex_colors() {
+        assert_eq!(hex_color("f80").unwrap().to_srgb(), (255, 136, 0, 1.0));
+        assert_eq!(hex_color("ff880080").unwrap().to_srgb(), (255, 136, 0, 128.0 / 255.0));
+        assert_eq!(hex_color("0000").unwrap().to_srgb(), (0, 0, 0, 0.0));
+        assert!(hex_color("12345").is_err());
+        assert!(hex_color("ggg").is_err());
+    }
+
+    #[test]
+    fn rgb_in_legacy_and_modern_syntax() {
+        assert_eq!(srgb("rgb", "255, 0, 0"), (255, 0, 0, 1.0));
+        assert_eq!(srgb("rgba", "0, 0, 255, 0.5"), (0, 0, 255, 0.5));
+        assert_eq!(srgb("rgb", "100% 50% 0% / 25%"), (255, 128, 0, 0.25));
+        // Aralık dışı kanallar ve alfa kırpılır
+        assert_eq!(srgb("rgb", "300 -5 0 / 2"), (255, 0, 0, 1.0));
+        assert!(parse_color_function("rgb", &tokens("1 2")).is_err());
+        assert!(parse_color_function("rgb", &tokens("1 2 3 / 4 / 5")).is_err());
+    }
+
+    #[test]
+    fn hsl_hues_in_every_angle_unit() {
+        assert_eq!(srgb("hsl", "0, 100%, 50%"), (255, 0, 0, 1.0));
+        assert_eq!(srgb("hsl", "120deg 100% 25%"), (0, 128, 0, 1.0));
+        assert_eq!(srgb("hsl", "0.5turn 100% 50%"), (0, 255, 255, 1.0));
+        assert_eq!(srgb("hsl", "400grad 100% 50%"), (255, 0, 0, 1.0));
+        assert_eq!(srgb("hsla", "-120 100% 50% / 50%"), (0, 0, 255, 0.5));
+        assert_eq!(srgb("hsl", "240 0% 100%"), (255, 255, 255, 1.0));
+    }
+
+    #[test]
+    fn hwb_colors() {
+        assert_eq!(srgb("hwb", "0 0% 0%"), (255, 0, 0, 1.0));
+        assert_eq!(srgb("hwb", "120 20% 20%"), (51, 204, 51, 1.0));
+        // Beyazlık ve siyahlık toplamı %100'ü aşarsa gri tonu oluşur
+        assert_eq!(srgb("hwb", "0 60% 60%"), (128, 128, 128, 1.0));
+    }
+
+    #[test]
+    fn lab_lch_and_oklch_convert_to_srgb() {
+        assert_eq!(srgb("lab", "100 0 0"), (255, 255, 255, 1.0));
+        assert_eq!(srgb("lab", "0 0 0"), (0, 0, 0, 1.0));
+        assert_eq!(srgb("lch", "50 0 0"), srgb("lab", "50 0 0"));
+        assert_eq!(srgb("oklch", "1 0 0"), (255, 255, 255, 1.0));
+        // oklch(62.8% 0.2577 29.23) sRGB kırmızısıdır
+        let (r, g, b, _) = srgb("oklch", "62.8% 0.2577 29.23");
+        assert!(r >= 254 && g <= 2 && b <= 2, "{:?}", (r, g, b));
+    }
+}
@@ -10,12 +10,31 @@ use crate::html::enums::AttrName;
 #[allow(dead_code)]
 pub enum Value {
     Length(f32, Unit),
+    Number(f32),
     Color(Color),
     Keyword(String),
     Display(Display), // Yeni eklendi
+    List(Vec<Value>), // Boşlukla ayrılmış çok bileşenli değerler
+    Tokens(Vec<Token>), // Özel özellik değerleri ve `var()` içeren, henüz çözülmemiş değerler
+    PendingSubstitution(String, Vec<Token>), // `var()` içeren kısaltmanın bir uzun biçimi: kısaltmanın adı ve çözülmemiş değeri
+    Math(MathExpr), // calc(), min(), max() ve clamp() ifadeleri
 }
-/// Represents CSS length units
+
+/// Represents a `calc()`, `min()`, `max()` or `clamp()` expression tree
 #[derive(Debug, Clone)]
+pub enum MathExpr {
+    Number(f32),
+    Length(f32, Unit),
+    Sum(Box<MathExpr>, Box<MathExpr>),
+    Difference(Box<MathExpr>, Box<MathExpr>),
+    Product(Box<MathExpr>, Box<MathExpr>),
+    Quotient(Box<MathExpr>, Box<MathExpr>),
+    Min(Vec<MathExpr>),
+    Max(Vec<MathExpr>),
+    Clamp(Box<MathExpr>, Box<MathExpr>, Box<MathExpr>),
+}
+/// Represents CSS length units
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Unit {
     Px,
     Em,
@@ -24,30 +43,61 @@ pub enum Unit {
     Vw,
     Vmin,
     Vmax,
+    Percent,
 }
 /// Represents CSS color values
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
+#[allow(dead_code, clippy::upper_case_acronyms)]
 pub enum Color {
     RGBA(u8, u8, u8, f32),
-    HSLA(f32, f32, f32, f32),
+    HSLA(f32, f32, f32, f32), // Ton (derece), doygunluk (%), açıklık (%), alfa
+    HWBA(f32, f32, f32, f32), // Ton (derece), beyazlık (%), siyahlık (%), alfa
+    LAB(f32, f32, f32, f32), // CIE Lab: L (0-100), a, b, alfa
+    LCH(f32, f32, f32, f32), // CIE LCH: L (0-100), kroma, ton (derece), alfa
+    OKLCH(f32, f32, f32, f32), // OKLCH: L (0-1), kroma, ton (derece), alfa
 }
 
 /// Represents CSS display property values
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum Display {
     Inline,
+    #[default]
     Block,
+    InlineBlock,
+    FlowRoot, // Yeni bir blok biçimlendirme bağlamı kuran blok
+    Contents, // Eleman kutu oluşturmaz, çocukları ebeveyninde yer alır
     None,
-    // Diğer display tipleri eklenebilir (inline-block, flex, grid vb.)
+    // Diğer display tipleri eklenebilir (flex, grid vb.)
 }
 
-impl Default for Display {
-    fn default() -> Self {
-        Display::Block // Varsayılan display tipi block
-    }
+/// Represents the `font-style` of a font face or element
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FontStyle {
+    #[default]
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// A font source listed in the `src` descriptor of `@font-face`
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    Url(String), // Çözülmüş (mutlak) adres
+    Local(String), // Sistemde yüklü fontun adı
+}
+
+/// Represents the origin of a stylesheet in the cascade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+pub enum Origin {
+    UserAgent,
+    User,
+    Author,
 }
 
+/// Selector specificity as (id, class, type) counts
+pub type Specificity = (usize, usize, usize);
+
 /// Represents a CSS selector
 #[derive(Debug, Clone)]
 pub enum Selector {
@@ -69,16 +119,16 @@ impl Selector {
     }
 
     // Özgüllük değerini hesaplar
-    pub fn specificity(&self) -> (usize, usize, usize) {
+    pub fn specificity(&self) -> Specificity {
         let Selector::Type(selector) = self;
         let mut specificity = (0, 0, 0);
-        if let Some(_) = &selector.id {
+        if selector.id.is_some() {
             specificity.0 += 1;
         }
         for _class in &selector.class {
             specificity.1 += 1;
         }
-        if let Some(_) = &selector.tag_name {
+        if selector.tag_name.is_some() {
             specificity.2 += 1;
         }
         specificity
@@ -88,17 +138,15 @@ impl Selector {
 impl SelectorType {
     pub fn matches(&self, element: &ElementData) -> bool {
         // Etiket adı eşleşmesi
-        if let Some(tag_name) = &self.tag_name {
-            if element.tag_name.to_string().to_lowercase() != *tag_name {
-                return false;
-            }
+        if let Some(tag_name) = &self.tag_name
+            && element.tag_name.to_string().to_lowercase() != *tag_name {
+            return false;
         }
 
         // ID eşleşmesi
-        if let Some(id) = &self.id {
-            if element.attributes.get(&AttrName::Id).map_or(true, |attr_id| attr_id != id) {
-                return false;
-            }
+        if let Some(id) = &self.id
+            && element.attributes.get(&AttrName::Id) != Some(id) {
+            return false;
         }
 
         // Sınıf eşleşmeleri
@@ -118,3 +166,63 @@ impl SelectorType {
     }
 }
 
+
+/// Represents a CSS token as defined by CSS Syntax Level 3
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Ident(String),
+    Function(String),
+    AtKeyword(String),
+    Hash(String, bool), // İkinci alan: değer geçerli bir tanımlayıcı mı (id seçicisi olabilir mi)
+    String(String),
+    BadString,
+    Url(String),
+    BadUrl,
+    Delim(char),
+    Number(f32),
+    Percentage(f32),
+    Dimension(f32, String),
+    Whitespace,
+    Cdo,
+    Cdc,
+    Colon,
+    Semicolon,
+    Comma,
+    OpenSquare,
+    CloseSquare,
+    OpenParen,
+    CloseParen,
+    OpenCurly,
+    CloseCurly,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "{}", name),
+            Token::Function(name) => write!(f, "{}(", name),
+            Token::AtKeyword(name) => write!(f, "@{}", name),
+            Token::Hash(name, _) => write!(f, "#{}", name),
+            Token::String(value) => write!(f, "\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+            Token::BadString => write!(f, "\""),
+            Token::Url(value) => write!(f, "url({})", value),
+            Token::BadUrl => write!(f, "url()"),
+            Token::Delim(c) => write!(f, "{}", c),
+            Token::Number(value) => write!(f, "{}", value),
+            Token::Percentage(value) => write!(f, "{}%", value),
+            Token::Dimension(value, unit) => write!(f, "{}{}", value, unit),
+            Token::Whitespace => write!(f, " "),
+            Token::Cdo => write!(f, "<!--"),
+            Token::Cdc => write!(f, "-->"),
+            Token::Colon => write!(f, ":"),
+            Token::Semicolon => write!(f, ";"),
+            Token::Comma => write!(f, ","),
+            Token::OpenSquare => write!(f, "["),
+            Token::CloseSquare => write!(f, "]"),
+            Token::OpenParen => write!(f, "("),
+            Token::CloseParen => write!(f, ")"),
+            Token::OpenCurly => write!(f, "{{"),
+            Token::CloseCurly => write!(f, "}}"),
+        }
+    }
+}
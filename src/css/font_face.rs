@@ -0,0 +1,179 @@
+//! `@font-face` descriptor parsing module
+//!
+//! This module parses the descriptors of `@font-face` rules into `FontFace` values.
+
+use super::enums::{FontSource, FontStyle, Token};
+use super::parser::{serialize_tokens, split_top_level, trim_whitespace};
+use super::structs::FontFace;
+use crate::resource::structs::resolve_url;
+
+type ParseResult<T> = Result<T, String>;
+
+/// Applies a single `@font-face` descriptor to the font face.
+/// `raw` is the descriptor value as written in the source, needed for `unicode-range`.
+pub fn apply_descriptor(face: &mut FontFace, name: &str, tokens: &[Token], raw: &str, base: Option<&str>) -> ParseResult<()> {
+    let tokens = trim_whitespace(tokens);
+    match name.to_lowercase().as_str() {
+        "font-family" => face.family = parse_family_name(tokens)?,
+        "src" => face.sources = parse_sources(tokens, base)?,
+        "font-weight" => face.weight = parse_weight_range(tokens)?,
+        "font-style" => face.style = parse_font_style(tokens)?,
+        "font-stretch" => face.stretch = parse_stretch_range(tokens)?,
+        "unicode-range" => face.unicode_range = parse_unicode_range(raw)?,
+        _ => return Err(format!("Unknown @font-face descriptor '{}'", name)),
+    }
+    Ok(())
+}
+
+// Tek bir aile adı: tırnaklı metin ya da boşlukla ayrılmış tanımlayıcılar
+fn parse_family_name(tokens: &[Token]) -> ParseResult<String> {
+    match tokens {
+        [Token::String(name)] => Ok(name.clone()),
+        _ if !tokens.is_empty() && tokens.iter().all(|token| matches!(token, Token::Ident(_) | Token::Whitespace)) => {
+            Ok(serialize_tokens(tokens).split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+        _ => Err(format!("Invalid font family name '{}'", serialize_tokens(tokens))),
+    }
+}
+
+// src: url(a.ttf) format("truetype"), local(Arial), ...
+// Desteklenmeyen biçimlerdeki (woff, woff2 vb.) kaynaklar atlanır
+fn parse_sources(tokens: &[Token], base: Option<&str>) -> ParseResult<Vec<FontSource>> {
+    let mut sources = vec![];
+    for source in split_top_level(tokens, &Token::Comma) {
+        let parts: Vec<&[Token]> = split_top_level(trim_whitespace(source), &Token::Whitespace).into_iter()
+            .filter(|part| !part.is_empty())
+            .collect();
+        match parts.as_slice() {
+            [[Token::Url(url)], format @ ..] if is_supported_format(format)? => {
+                sources.push(FontSource::Url(resolve_url(base, url)));
+            }
+            [[Token::Function(function), Token::String(url), Token::CloseParen], format @ ..]
+                if function.eq_ignore_ascii_case("url") && is_supported_format(format)? => {
+                sources.push(FontSource::Url(resolve_url(base, url)));
+            }
+            [[Token::Function(function), name @ .., Token::CloseParen]] if function.eq_ignore_ascii_case("local") => {
+                sources.push(FontSource::Local(parse_family_name(trim_whitespace(name))?));
+            }
+            [[Token::Url(_)], ..] | [[Token::Function(_), ..], ..] => {}
+            _ => return Err(format!("Invalid font source '{}'", serialize_tokens(source))),
+        }
+    }
+    if sources.is_empty() {
+        return Err("No supported font source in 'src'".to_string());
+    }
+    Ok(sources)
+}
+
+// `format()` ipucu yoksa kaynak denenir; varsa yalnızca TrueType/OpenType kabul edilir
+fn is_supported_format(format: &[&[Token]]) -> ParseResult<bool> {
+    match format {
+        [] => Ok(true),
+        [[Token::Function(function), hint @ .., Token::CloseParen]] if function.eq_ignore_ascii_case("format") => {
+            Ok(match trim_whitespace(hint) {
+                [Token::String(format)] | [Token::Ident(format)] => {
+                    matches!(format.to_lowercase().as_str(), "truetype" | "opentype" | "collection")
+                }
+                _ => false,
+            })
+        }
+        _ => Err(format!("Invalid font format hint '{}'", format.iter().map(|part| serialize_tokens(part)).collect::<Vec<_>>().join(" "))),
+    }
+}
+
+// font-weight: normal | bold | <sayı> | <sayı> <sayı> (değişken fontlar için aralık)
+fn parse_weight_range(tokens: &[Token]) -> ParseResult<(f32, f32)> {
+    let weights = split_top_level(tokens, &Token::Whitespace).into_iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| match part {
+            [Token::Ident(keyword)] if keyword.eq_ignore_ascii_case("normal") => Ok(400.0),
+            [Token::Ident(keyword)] if keyword.eq_ignore_ascii_case("bold") => Ok(700.0),
+            [Token::Number(weight)] if (1.0..=1000.0).contains(weight) => Ok(*weight),
+            _ => Err(format!("Invalid font weight '{}'", serialize_tokens(part))),
+        })
+        .collect::<ParseResult<Vec<f32>>>()?;
+    match weights.as_slice() {
+        [weight] => Ok((*weight, *weight)),
+        [min, max] => Ok((min.min(*max), min.max(*max))),
+        _ => Err(format!("Invalid font weight '{}'", serialize_tokens(tokens))),
+    }
+}
+
+/// Returns the width percentage of a `font-stretch` keyword (e.g. `condensed` is 75%)
+pub fn font_stretch_percentage(keyword: &str) -> Option<f32> {
+    match keyword.to_lowercase().as_str() {
+        "ultra-condensed" => Some(50.0),
+        "extra-condensed" => Some(62.5),
+        "condensed" => Some(75.0),
+        "semi-condensed" => Some(87.5),
+        "normal" => Some(100.0),
+        "semi-expanded" => Some(112.5),
+        "expanded" => Some(125.0),
+        "extra-expanded" => Some(150.0),
+        "ultra-expanded" => Some(200.0),
+        _ => None,
+    }
+}
+
+// font-stretch: <anahtar kelime> | <yüzde> | <yüzde> <yüzde> (değişken fontlar için aralık)
+fn parse_stretch_range(tokens: &[Token]) -> ParseResult<(f32, f32)> {
+    let stretches = split_top_level(tokens, &Token::Whitespace).into_iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| match part {
+            [Token::Ident(keyword)] => font_stretch_percentage(keyword),
+            [Token::Percentage(percentage)] if *percentage > 0.0 => Some(*percentage),
+            _ => None,
+        }.ok_or_else(|| format!("Invalid font stretch '{}'", serialize_tokens(part))))
+        .collect::<ParseResult<Vec<f32>>>()?;
+    match stretches.as_slice() {
+        [stretch] => Ok((*stretch, *stretch)),
+        [min, max] => Ok((min.min(*max), min.max(*max))),
+        _ => Err(format!("Invalid font stretch '{}'", serialize_tokens(tokens))),
+    }
+}
+
+// font-style: normal | italic | oblique [açı]; eğiklik açısı yok sayılır
+fn parse_font_style(tokens: &[Token]) -> ParseResult<FontStyle> {
+    match trim_whitespace(tokens).first() {
+        Some(Token::Ident(keyword)) if keyword.eq_ignore_ascii_case("normal") => Ok(FontStyle::Normal),
+        Some(Token::Ident(keyword)) if keyword.eq_ignore_ascii_case("italic") => Ok(FontStyle::Italic),
+        Some(Token::Ident(keyword)) if keyword.eq_ignore_ascii_case("oblique") => Ok(FontStyle::Oblique),
+        _ => Err(format!("Invalid font style '{}'", serialize_tokens(tokens))),
+    }
+}
+
+// unicode-range: U+0026, U+0000-00FF, U+4?? gibi aralıkların listesi.
+// Onaltılık rakamlar tokenizer'da sayı ve boyut olarak bölündüğünden kaynak metin üzerinden okunur.
+fn parse_unicode_range(raw: &str) -> ParseResult<Vec<(u32, u32)>> {
+    raw.split(',').map(|range| {
+        let range = range.trim();
+        let invalid = || format!("Invalid unicode range '{}'", range);
+        let digits = range.strip_prefix("U+").or_else(|| range.strip_prefix("u+")).ok_or_else(invalid)?;
+        let hex = |digits: &str| match digits.len() {
+            1..=6 => u32::from_str_radix(digits, 16).ok(),
+            _ => None,
+        };
+        let (start, end) = match digits.split_once('-') {
+            Some((start, end)) => (hex(start).ok_or_else(invalid)?, hex(end).ok_or_else(invalid)?),
+            // `?` joker karakterleri aralığın başında 0, sonunda F olarak okunur
+            None if digits.contains('?') => {
+                let prefix = digits.trim_end_matches('?');
+                if prefix.contains('?') || digits.len() > 6 {
+                    return Err(invalid());
+                }
+                let wildcards = digits.len() - prefix.len();
+                let start = hex(&format!("{}{}", prefix, "0".repeat(wildcards))).ok_or_else(invalid)?;
+                let end = hex(&format!("{}{}", prefix, "F".repeat(wildcards))).ok_or_else(invalid)?;
+                (start, end)
+            }
+            None => {
+                let codepoint = hex(digits).ok_or_else(invalid)?;
+                (codepoint, codepoint)
+            }
+        };
+        if start > end || end > 0x10FFFF {
+            return Err(invalid());
+        }
+        Ok((start, end))
+    }).collect()
+}
@@ -0,0 +1,280 @@
+//! CSS math functions module
+//!
+//! This module parses `calc()`, `min()`, `max()` and `clamp()` into expression trees
+//! and evaluates them once relative lengths and percentages can be resolved.
+
+use super::enums::{MathExpr, Token, Unit};
+use super::parser::{parse_unit, serialize_tokens, split_top_level, trim_whitespace};
+
+type ParseResult<T> = Result<T, String>;
+
+/// Returns true if the function name is a supported math function
+pub fn is_math_function(name: &str) -> bool {
+    matches!(name.to_lowercase().as_str(), "calc" | "min" | "max" | "clamp")
+}
+
+/// Parses the arguments of a math function into an expression tree
+pub fn parse_math_function(name: &str, args: &[Token]) -> ParseResult<MathExpr> {
+    let expr = parse_function(name, args)?;
+    // Sonuç bir uzunluk ya da sayı olmalı; uzunluk * uzunluk gibi ifadeler geçersizdir
+    expr.kind().ok_or_else(|| format!("Invalid {} value: {}({})", name, name, serialize_tokens(args)))?;
+    Ok(expr)
+}
+
+fn parse_function(name: &str, args: &[Token]) -> ParseResult<MathExpr> {
+    let mut arguments = split_top_level(args, &Token::Comma).into_iter()
+        .map(|arg| parse_sum(trim_whitespace(arg)))
+        .collect::<ParseResult<Vec<MathExpr>>>()?;
+    match (name.to_lowercase().as_str(), arguments.len()) {
+        ("calc", 1) => Ok(arguments.remove(0)),
+        ("min", n) if n > 0 => Ok(MathExpr::Min(arguments)),
+        ("max", n) if n > 0 => Ok(MathExpr::Max(arguments)),
+        ("clamp", 3) => {
+            let max = arguments.pop().unwrap();
+            let value = arguments.pop().unwrap();
+            let min = arguments.pop().unwrap();
+            Ok(MathExpr::Clamp(Box::new(min), Box::new(value), Box::new(max)))
+        }
+        _ => Err(format!("Wrong number of arguments for {}(): {}", name, arguments.len())),
+    }
+}
+
+// Bir ifade parçasını, verilen işleçlerin en üst düzeydeki konumlarından böler.
+// Sonuç, ilk terim ve ardından gelen (işleç, terim) çiftleridir.
+fn split_operators<'t>(tokens: &'t [Token], operators: &[char]) -> (&'t [Token], Vec<(char, &'t [Token])>) {
+    let mut depth = 0;
+    let mut pieces = vec![];
+    let mut start = 0;
+    let mut pending: Option<char> = None;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Function(_) | Token::OpenParen => depth += 1,
+            Token::CloseParen => depth -= 1,
+            Token::Delim(op) if depth == 0 && operators.contains(op) => {
+                pieces.push((pending, &tokens[start..index]));
+                pending = Some(*op);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    pieces.push((pending, &tokens[start..]));
+
+    let mut pieces = pieces.into_iter();
+    let (_, first) = pieces.next().unwrap();
+    let rest = pieces.map(|(op, piece)| (op.unwrap(), piece)).collect();
+    (first, rest)
+}
+
+// toplam := çarpım (('+' | '-') çarpım)*
+fn parse_sum(tokens: &[Token]) -> ParseResult<MathExpr> {
+    let (first, rest) = split_operators(tokens, &['+', '-']);
+    // `+` ve `-` işleçlerinin iki yanında boşluk bulunmalıdır
+    for (_, piece) in &rest {
+        if piece.first() != Some(&Token::Whitespace) {
+            return Err("Expected whitespace around '+' and '-' in math expression".to_string());
+        }
+    }
+    if !rest.is_empty() && first.last() != Some(&Token::Whitespace) {
+        return Err("Expected whitespace around '+' and '-' in math expression".to_string());
+    }
+    let mut expr = parse_product(trim_whitespace(first))?;
+    for (op, piece) in rest {
+        let right = Box::new(parse_product(trim_whitespace(piece))?);
+        expr = match op {
+            '+' => MathExpr::Sum(Box::new(expr), right),
+            _ => MathExpr::Difference(Box::new(expr), right),
+        };
+    }
+    Ok(expr)
+}
+
+// çarpım := değer (('*' | '/') değer)*
+fn parse_product(tokens: &[Token]) -> ParseResult<MathExpr> {
+    let (first, rest) = split_operators(tokens, &['*', '/']);
+    let mut expr = parse_term(trim_whitespace(first))?;
+    for (op, piece) in rest {
+        let right = Box::new(parse_term(trim_whitespace(piece))?);
+        // Bölen bir sayıdır ve ayrıştırma sırasında hesaplanabilir; sıfıra bölen ifade geçersizdir
+        if op == '/' && right.kind() == Some(MathKind::Number) && right.evaluate(&|_, _| None) == Some(0.0) {
+            return Err(format!("Division by zero in math expression: '{}'", serialize_tokens(tokens)));
+        }
+        expr = match op {
+            '*' => MathExpr::Product(Box::new(expr), right),
+            _ => MathExpr::Quotient(Box::new(expr), right),
+        };
+    }
+    Ok(expr)
+}
+
+// değer := sayı | boyut | yüzde | '(' toplam ')' | iç içe matematik fonksiyonu
+fn parse_term(tokens: &[Token]) -> ParseResult<MathExpr> {
+    match tokens {
+        [Token::Number(number)] => Ok(MathExpr::Number(*number)),
+        [Token::Percentage(percentage)] => Ok(MathExpr::Length(*percentage, Unit::Percent)),
+        [Token::Dimension(value, unit)] => match parse_unit(unit) {
+            Some(unit) => Ok(MathExpr::Length(*value, unit)),
+            None => Err(format!("Unknown unit '{}'", unit)),
+        },
+        [Token::OpenParen, inner @ .., Token::CloseParen] => parse_sum(trim_whitespace(inner)),
+        [Token::Function(name), args @ .., Token::CloseParen] if is_math_function(name) => parse_function(name, args),
+        [] => Err("Missing value in math expression".to_string()),
+        _ => Err(format!("Unexpected value in math expression: '{}'", serialize_tokens(tokens))),
+    }
+}
+
+/// The type of a math expression result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MathKind {
+    Number,
+    Length,
+}
+
+impl MathExpr {
+    /// Returns the result type, or `None` if the expression mixes incompatible types
+    pub fn kind(&self) -> Option<MathKind> {
+        let same = |values: &mut dyn Iterator<Item = &MathExpr>| {
+            let kinds = values.map(|value| value.kind()).collect::<Option<Vec<_>>>()?;
+            let first = *kinds.first()?;
+            kinds.iter().all(|&kind| kind == first).then_some(first)
+        };
+        match self {
+            MathExpr::Number(_) => Some(MathKind::Number),
+            MathExpr::Length(..) => Some(MathKind::Length),
+            MathExpr::Sum(a, b) | MathExpr::Difference(a, b) => same(&mut [a.as_ref(), b.as_ref()].into_iter()),
+            // Çarpımda en az bir taraf sayı olmalıdır
+            MathExpr::Product(a, b) => match (a.kind()?, b.kind()?) {
+                (MathKind::Length, MathKind::Length) => None,
+                (MathKind::Number, MathKind::Number) => Some(MathKind::Number),
+                _ => Some(MathKind::Length),
+            },
+            // Bölende yalnızca sayı kullanılabilir
+            MathExpr::Quotient(a, b) => match b.kind()? {
+                MathKind::Number => a.kind(),
+                MathKind::Length => None,
+            },
+            MathExpr::Min(values) | MathExpr::Max(values) => same(&mut values.iter()),
+            MathExpr::Clamp(min, value, max) => same(&mut [min.as_ref(), value.as_ref(), max.as_ref()].into_iter()),
+        }
+    }
+
+    /// Evaluates the expression, resolving each length to pixels through `resolve`.
+    /// Returns `None` if some length cannot be resolved yet (e.g. a percentage before layout).
+    pub fn evaluate(&self, resolve: &dyn Fn(f32, Unit) -> Option<f32>) -> Option<f32> {
+        let result = match self {
+            MathExpr::Number(number) => *number,
+            MathExpr::Length(value, unit) => resolve(*value, *unit)?,
+            MathExpr::Sum(a, b) => a.evaluate(resolve)? + b.evaluate(resolve)?,
+            MathExpr::Difference(a, b) => a.evaluate(resolve)? - b.evaluate(resolve)?,
+            MathExpr::Product(a, b) => a.evaluate(resolve)? * b.evaluate(resolve)?,
+            MathExpr::Quotient(a, b) => a.evaluate(resolve)? / b.evaluate(resolve)?,
+            MathExpr::Min(values) => values.iter()
+                .map(|value| value.evaluate(resolve))
+                .collect::<Option<Vec<_>>>()?
+                .into_iter().fold(f32::INFINITY, f32::min),
+            MathExpr::Max(values) => values.iter()
+                .map(|value| value.evaluate(resolve))
+                .collect::<Option<Vec<_>>>()?
+                .into_iter().fold(f32::NEG_INFINITY, f32::max),
+            // clamp(min, değer, max) = max(min, min(değer, max))
+            MathExpr::Clamp(min, value, max) => value.evaluate(resolve)?.min(max.evaluate(resolve)?).max(min.evaluate(resolve)?),
+        };
+        Some(result)
+    }
+
+    /// Replaces every length leaf using `resolve`; leaves for which it returns `None` are kept
+    pub fn map_lengths(&self, resolve: &dyn Fn(f32, Unit) -> Option<f32>) -> MathExpr {
+        let map = |expr: &MathExpr| Box::new(expr.map_lengths(resolve));
+        match self {
+            MathExpr::Number(number) => MathExpr::Number(*number),
+            MathExpr::Length(value, unit) => match resolve(*value, *unit) {
+                Some(px) => MathExpr::Length(px, Unit::Px),
+                None => MathExpr::Length(*value, *unit),
+            },
+            MathExpr::Sum(a, b) => MathExpr::Sum(map(a), map(b)),
+            MathExpr::Difference(a, b) => MathExpr::Difference(map(a), map(b)),
+            MathExpr::Product(a, b) => MathExpr::Product(map(a), map(b)),
+            MathExpr::Quotient(a, b) => MathExpr::Quotient(map(a), map(b)),
+            MathExpr::Min(values) => MathExpr::Min(values.iter().map(|value| value.map_lengths(resolve)).collect()),
+            MathExpr::Max(values) => MathExpr::Max(values.iter().map(|value| value.map_lengths(resolve)).collect()),
+            MathExpr::Clamp(min, value, max) => MathExpr::Clamp(map(min), map(value), map(max)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::tokenizer::Tokenizer;
+
+    fn parse(name: &str, args: &str) -> ParseResult<MathExpr> {
+        let tokens: Vec<Token> = Tokenizer::new(args).tokenize().into_iter().map(|(token, _)| token).collect();
+        parse_math_function(name, &tokens)
+    }
+
+    // 1em = 10px, %100 = 200px olan bir bağlamda değerlendirir
+    fn evaluate(name: &str, args: &str) -> Option<f32> {
+        parse(name, args).unwrap().evaluate(&|value, unit| match unit {
+            Unit::Px => Some(value),
+            Unit::Em => Some(value * 10.0),
+            Unit::Percent => Some(value * 2.0),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn calc_respects_operator_precedence() {
+        assert_eq!(evaluate("calc", "1px + 2px * 3"), Some(7.0));
+        assert_eq!(evaluate("calc", "(1px + 2px) * 3"), Some(9.0));
+        assert_eq!(evaluate("calc", "10px - 4px - 3px"), Some(3.0));
+        assert_eq!(evaluate("calc", "12px / 2 / 3"), Some(2.0));
+        assert_eq!(evaluate("calc", "2 * calc(1em + 50%)"), Some(220.0));
+    }
+
+    #[test]
+    fn min_max_and_clamp() {
+        assert_eq!(evaluate("min", "10px, 2em, 3%"), Some(6.0));
+        assert_eq!(evaluate("max", "10px, 2em, 3%"), Some(20.0));
+        assert_eq!(evaluate("clamp", "10px, 50%, 30px"), Some(30.0));
+        assert_eq!(evaluate("clamp", "10px, 1%, 30px"), Some(10.0));
+        // Alt sınır üst sınırdan büyükse alt sınır kazanır
+        assert_eq!(evaluate("clamp", "40px, 1px, 30px"), Some(40.0));
+    }
+
+    #[test]
+    fn result_kinds() {
+        assert_eq!(parse("calc", "1 + 2").unwrap().kind(), Some(MathKind::Number));
+        assert_eq!(parse("calc", "2 * 3px").unwrap().kind(), Some(MathKind::Length));
+        // Uzunluklar çarpılamaz, sayı ve uzunluk toplanamaz, uzunluğa bölünemez
+        assert!(parse("calc", "2px * 3px").is_err());
+        assert!(parse("calc", "1 + 2px").is_err());
+        assert!(parse("calc", "2px / 1px").is_err());
+    }
+
+    #[test]
+    fn division_by_zero_is_invalid() {
+        assert!(parse("calc", "1px / 0").is_err());
+        assert!(parse("calc", "1px / (2 - 2)").is_err());
+        assert!(parse("calc", "1 / min(0, 1)").is_err());
+        assert!(parse("max", "1px, 2px / -0").is_err());
+        assert_eq!(evaluate("calc", "1px / (2 - 1.5)"), Some(2.0));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert!(parse("calc", "1px, 2px").is_err());
+        assert!(parse("clamp", "1px, 2px").is_err());
+        assert!(parse("min", "").is_err());
+    }
+
+    #[test]
+    fn unresolved_lengths_are_kept() {
+        let expr = parse("calc", "100% - 2em").unwrap();
+        assert_eq!(expr.evaluate(&|value, unit| (unit == Unit::Px).then_some(value)), None);
+        let mapped = expr.map_lengths(&|value, unit| (unit == Unit::Em).then_some(value * 16.0));
+        assert_eq!(mapped.evaluate(&|value, unit| match unit {
+            Unit::Percent => Some(value * 5.0),
+            _ => Some(value),
+        }), Some(468.0));
+    }
+}
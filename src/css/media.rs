@@ -0,0 +1,456 @@
+//! CSS media queries module
+//!
+//! This module parses `@media` query lists and evaluates them against the viewport
+//! and a configurable media environment.
+
+use super::enums::{Token, Unit};
+use super::parser::{parse_unit, split_top_level, trim_whitespace};
+
+/// The media type the document is rendered for
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[allow(dead_code)]
+pub enum MediaType {
+    #[default]
+    Screen,
+    Print,
+}
+
+/// The user's preferred color scheme
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[allow(dead_code)]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// Properties of the rendering environment that media queries are evaluated against
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MediaEnvironment {
+    pub media_type: MediaType,
+    pub color_scheme: ColorScheme,
+}
+
+/// A comma-separated list of media queries; it matches when any query matches
+#[derive(Debug, Clone)]
+pub struct MediaQueryList(pub Vec<MediaQuery>);
+
+/// A single media query such as `not screen and (min-width: 600px)`
+#[derive(Debug, Clone)]
+pub struct MediaQuery {
+    pub negated: bool,
+    pub media_type: Option<String>,
+    pub condition: Option<MediaCondition>,
+}
+
+/// A media condition built from media features and `not`/`and`/`or`
+#[derive(Debug, Clone)]
+pub enum MediaCondition {
+    Feature(MediaFeature),
+    Not(Box<MediaCondition>),
+    And(Vec<MediaCondition>),
+    Or(Vec<MediaCondition>),
+    Unknown, // Tanınmayan ifadeler hiçbir zaman eşleşmez
+}
+
+/// A media feature test; `min-`/`max-` prefixes and range syntax become comparisons
+#[derive(Debug, Clone)]
+pub struct MediaFeature {
+    pub name: String,
+    pub comparison: Option<(Comparison, MediaValue)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Equal,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+#[derive(Debug, Clone)]
+pub enum MediaValue {
+    Length(f32, Unit),
+    Number(f32),
+    Ratio(f32, f32),
+    Ident(String),
+}
+
+impl MediaQueryList {
+    /// Parses the prelude of an `@media` rule; an empty prelude matches everything
+    pub fn parse(tokens: &[Token]) -> MediaQueryList {
+        let tokens = trim_whitespace(tokens);
+        if tokens.is_empty() {
+            return MediaQueryList(vec![]);
+        }
+        // Geçersiz bir sorgu yalnızca kendisini `not all` yapar, listenin geri kalanı geçerli kalır
+        MediaQueryList(split_top_level(tokens, &Token::Comma).into_iter()
+            .map(|query| parse_query(trim_whitespace(query)).unwrap_or(MediaQuery {
+                negated: true,
+                media_type: Some("all".to_string()),
+                condition: None,
+            }))
+            .collect())
+    }
+
+    /// Returns true if any query in the list matches the environment and viewport size
+    pub fn matches(&self, environment: &MediaEnvironment, width: f32, height: f32) -> bool {
+        self.0.is_empty() || self.0.iter().any(|query| query.matches(environment, width, height))
+    }
+}
+
+impl MediaQuery {
+    fn matches(&self, environment: &MediaEnvironment, width: f32, height: f32) -> bool {
+        let type_matches = match self.media_type.as_deref() {
+            None | Some("all") => true,
+            Some("screen") => environment.media_type == MediaType::Screen,
+            Some("print") => environment.media_type == MediaType::Print,
+            Some(_) => false,
+        };
+        let condition_matches = self.condition.as_ref()
+            .is_none_or(|condition| condition.matches(environment, width, height));
+        (type_matches && condition_matches) != self.negated
+    }
+}
+
+impl MediaCondition {
+    fn matches(&self, environment: &MediaEnvironment, width: f32, height: f32) -> bool {
+        match self {
+            MediaCondition::Feature(feature) => feature.matches(environment, width, height),
+            MediaCondition::Not(condition) => !condition.matches(environment, width, height),
+            MediaCondition::And(conditions) => conditions.iter().all(|c| c.matches(environment, width, height)),
+            MediaCondition::Or(conditions) => conditions.iter().any(|c| c.matches(environment, width, height)),
+            MediaCondition::Unknown => false,
+        }
+    }
+}
+
+impl MediaFeature {
+    fn matches(&self, environment: &MediaEnvironment, width: f32, height: f32) -> bool {
+        // Ortamın bu özellik için değeri; bilinmeyen özellikler eşleşmez
+        let actual = match self.name.as_str() {
+            "width" => MediaValue::Length(width, Unit::Px),
+            "height" => MediaValue::Length(height, Unit::Px),
+            "aspect-ratio" => MediaValue::Ratio(width, height),
+            "orientation" => MediaValue::Ident(if height >= width { "portrait" } else { "landscape" }.to_string()),
+            "prefers-color-scheme" => MediaValue::Ident(match environment.color_scheme {
+                ColorScheme::Light => "light",
+                ColorScheme::Dark => "dark",
+            }.to_string()),
+            "color" => MediaValue::Number(8.0),
+            "monochrome" => MediaValue::Number(0.0),
+            _ => return false,
+        };
+        let Some((comparison, expected)) = &self.comparison else {
+            // Değersiz (boolean) özellik, değeri sıfır ya da `none` değilse eşleşir
+            return match actual {
+                MediaValue::Length(value, _) | MediaValue::Number(value) => value != 0.0,
+                MediaValue::Ratio(a, _) => a != 0.0,
+                MediaValue::Ident(_) => true,
+            };
+        };
+        match (&actual, expected) {
+            (MediaValue::Ident(actual), MediaValue::Ident(expected)) => {
+                *comparison == Comparison::Equal && actual.eq_ignore_ascii_case(expected)
+            }
+            _ => {
+                let (Some(actual), Some(expected)) = (actual.to_number(width, height), expected.to_number(width, height)) else {
+                    return false;
+                };
+                match comparison {
+                    Comparison::Equal => (actual - expected).abs() < 0.001,
+                    Comparison::Less => actual < expected,
+                    Comparison::LessEqual => actual <= expected,
+                    Comparison::Greater => actual > expected,
+                    Comparison::GreaterEqual => actual >= expected,
+                }
+            }
+        }
+    }
+}
+
+impl MediaValue {
+    // Karşılaştırma için sayısal değer; medya sorgularında em ve rem başlangıç font boyutuna (16px) göredir
+    fn to_number(&self, width: f32, height: f32) -> Option<f32> {
+        match self {
+            MediaValue::Number(number) => Some(*number),
+            MediaValue::Ratio(a, b) => Some(a / b),
+            MediaValue::Length(value, unit) => match unit {
+                Unit::Px => Some(*value),
+                Unit::Em | Unit::Rem => Some(value * 16.0),
+                Unit::Vw => Some(value * width / 100.0),
+                Unit::Vh => Some(value * height / 100.0),
+                Unit::Vmin => Some(value * width.min(height) / 100.0),
+                Unit::Vmax => Some(value * width.max(height) / 100.0),
+                Unit::Percent => None,
+            },
+            MediaValue::Ident(_) => None,
+        }
+    }
+}
+
+// Boşlukla ayrılmış bileşenler; parantez grupları tek bir bileşen sayılır
+fn components(tokens: &[Token]) -> Vec<&[Token]> {
+    split_top_level(tokens, &Token::Whitespace).into_iter()
+        .filter(|component| !component.is_empty())
+        .collect()
+}
+
+fn is_keyword(component: &[Token], keyword: &str) -> bool {
+    matches!(component, [Token::Ident(ident)] if ident.eq_ignore_ascii_case(keyword))
+}
+
+// [not | only]? <medya-tipi> [and <koşul>]?  ya da yalnızca <koşul>
+fn parse_query(tokens: &[Token]) -> Option<MediaQuery> {
+    let parts = components(tokens);
+    // `not` yalnızca ardından bir medya tipi geliyorsa sorgunun tamamını olumsuzlar
+    let (negated, rest) = match parts.first() {
+        Some(first) if is_keyword(first, "not") && matches!(parts.get(1), Some([Token::Ident(_)])) => (true, &parts[1..]),
+        Some(first) if is_keyword(first, "only") => (false, &parts[1..]),
+        _ => (false, &parts[..]),
+    };
+    // Medya tipi yoksa sorgu yalnızca bir koşuldur (örn: `not (width < 500px)`)
+    let media_type = match rest.first() {
+        Some([Token::Ident(media_type)]) if !is_keyword(rest[0], "not") => media_type.to_lowercase(),
+        _ if rest.len() == parts.len() => {
+            return Some(MediaQuery { negated: false, media_type: None, condition: Some(parse_condition(&parts)?) });
+        }
+        _ => return None,
+    };
+    if matches!(media_type.as_str(), "only" | "and" | "or") {
+        return None;
+    }
+    let condition = match &rest[1..] {
+        [] => None,
+        // Medya tipinden sonra `or` kullanılamaz
+        [and, condition @ ..] if is_keyword(and, "and") && !condition.iter().any(|part| is_keyword(part, "or")) => {
+            Some(parse_condition(condition)?)
+        }
+        _ => return None,
+    };
+    Some(MediaQuery { negated, media_type: Some(media_type), condition })
+}
+
+// not <parantezli> | <parantezli> [and <parantezli>]* | <parantezli> [or <parantezli>]*
+fn parse_condition(parts: &[&[Token]]) -> Option<MediaCondition> {
+    match parts {
+        [] => None,
+        [not, operand] if is_keyword(not, "not") => Some(MediaCondition::Not(Box::new(parse_in_parens(operand)?))),
+        [first, rest @ ..] => {
+            let mut conditions = vec![parse_in_parens(first)?];
+            let mut operator: Option<String> = None;
+            for pair in rest.chunks(2) {
+                let [keyword, operand] = pair else { return None };
+                let keyword = match keyword {
+                    [Token::Ident(keyword)] => keyword.to_lowercase(),
+                    _ => return None,
+                };
+                // `and` ve `or` parantez olmadan karıştırılamaz
+                if !matches!(keyword.as_str(), "and" | "or") || operator.as_ref().is_some_and(|op| *op != keyword) {
+                    return None;
+                }
+                operator = Some(keyword);
+                conditions.push(parse_in_parens(operand)?);
+            }
+            Some(match operator.as_deref() {
+                None => conditions.remove(0),
+                Some("and") => MediaCondition::And(conditions),
+                _ => MediaCondition::Or(conditions),
+            })
+        }
+    }
+}
+
+// ( <koşul> ) | ( <özellik> ); tanınmayan parantezli ifadeler bilinmeyen sayılır
+fn parse_in_parens(component: &[Token]) -> Option<MediaCondition> {
+    match component {
+        [Token::OpenParen, inner @ .., Token::CloseParen] => {
+            let inner = trim_whitespace(inner);
+            if matches!(inner.first(), Some(Token::OpenParen)) || is_keyword(components(inner).first().copied().unwrap_or(&[]), "not") {
+                return Some(parse_condition(&components(inner)).unwrap_or(MediaCondition::Unknown));
+            }
+            Some(parse_feature(inner).unwrap_or(MediaCondition::Unknown))
+        }
+        [Token::Function(_), .., Token::CloseParen] => Some(MediaCondition::Unknown),
+        _ => None,
+    }
+}
+
+// Medya özelliği: `ad`, `ad: değer` ya da aralık sözdizimi (`400px <= width < 800px`)
+fn parse_feature(tokens: &[Token]) -> Option<MediaCondition> {
+    let feature = |name: &str, comparison| MediaCondition::Feature(MediaFeature { name: name.to_lowercase(), comparison });
+    match tokens {
+        [Token::Ident(name)] => return Some(feature(name, None)),
+        [Token::Ident(name), rest @ ..] if trim_whitespace(rest).first() == Some(&Token::Colon) => {
+            let value = parse_media_value(trim_whitespace(&trim_whitespace(rest)[1..]))?;
+            let name = name.to_lowercase();
+            return Some(match (name.strip_prefix("min-"), name.strip_prefix("max-")) {
+                (Some(name), _) => feature(name, Some((Comparison::GreaterEqual, value))),
+                (_, Some(name)) => feature(name, Some((Comparison::LessEqual, value))),
+                _ => feature(&name, Some((Comparison::Equal, value))),
+            });
+        }
+        _ => {}
+    }
+
+    // Aralık sözdizimi: işleçlerle ayrılmış iki ya da üç terim
+    let tokens: Vec<&Token> = tokens.iter().filter(|token| **token != Token::Whitespace).collect();
+    let mut operands: Vec<Vec<Token>> = vec![vec![]];
+    let mut operators = vec![];
+    let mut index = 0;
+    while index < tokens.len() {
+        let comparison = match (tokens[index], tokens.get(index + 1)) {
+            (Token::Delim('<'), Some(Token::Delim('='))) => Some((Comparison::LessEqual, 2)),
+            (Token::Delim('>'), Some(Token::Delim('='))) => Some((Comparison::GreaterEqual, 2)),
+            (Token::Delim('<'), _) => Some((Comparison::Less, 1)),
+            (Token::Delim('>'), _) => Some((Comparison::Greater, 1)),
+            (Token::Delim('='), _) => Some((Comparison::Equal, 1)),
+            _ => None,
+        };
+        match comparison {
+            Some((comparison, length)) => {
+                operators.push(comparison);
+                operands.push(vec![]);
+                index += length;
+            }
+            None => {
+                operands.last_mut().unwrap().push(tokens[index].clone());
+                index += 1;
+            }
+        }
+    }
+
+    // `a < ad` biçimindeki karşılaştırma `ad > a` olarak çevrilir
+    let flip = |comparison| match comparison {
+        Comparison::Less => Comparison::Greater,
+        Comparison::LessEqual => Comparison::GreaterEqual,
+        Comparison::Greater => Comparison::Less,
+        Comparison::GreaterEqual => Comparison::LessEqual,
+        Comparison::Equal => Comparison::Equal,
+    };
+    let name = |operand: &[Token]| match operand {
+        [Token::Ident(name)] => Some(name.clone()),
+        _ => None,
+    };
+    match (operands.as_slice(), operators.as_slice()) {
+        ([left, right], [comparison]) => match (name(left), name(right)) {
+            (Some(feature_name), None) => Some(feature(&feature_name, Some((*comparison, parse_media_value(right)?)))),
+            (None, Some(feature_name)) => Some(feature(&feature_name, Some((flip(*comparison), parse_media_value(left)?)))),
+            _ => None,
+        },
+        ([low, middle, high], [first, second]) => {
+            // İki işleç de aynı yönde olmalıdır
+            let ascending = matches!(first, Comparison::Less | Comparison::LessEqual) && matches!(second, Comparison::Less | Comparison::LessEqual);
+            let descending = matches!(first, Comparison::Greater | Comparison::GreaterEqual) && matches!(second, Comparison::Greater | Comparison::GreaterEqual);
+            if !ascending && !descending {
+                return None;
+            }
+            let feature_name = name(middle)?;
+            Some(MediaCondition::And(vec![
+                feature(&feature_name, Some((flip(*first), parse_media_value(low)?))),
+                feature(&feature_name, Some((*second, parse_media_value(high)?))),
+            ]))
+        }
+        _ => None,
+    }
+}
+
+fn parse_media_value(tokens: &[Token]) -> Option<MediaValue> {
+    let tokens: Vec<&Token> = tokens.iter().filter(|token| **token != Token::Whitespace).collect();
+    match tokens.as_slice() {
+        [Token::Number(a), Token::Delim('/'), Token::Number(b)] => Some(MediaValue::Ratio(*a, *b)),
+        [Token::Number(number)] => Some(MediaValue::Number(*number)),
+        [Token::Dimension(value, unit)] => parse_unit(unit).map(|unit| MediaValue::Length(*value, unit)),
+        [Token::Ident(ident)] => Some(MediaValue::Ident(ident.to_lowercase())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::tokenizer::Tokenizer;
+
+    fn matches(query: &str, environment: MediaEnvironment, width: f32, height: f32) -> bool {
+        let tokens: Vec<Token> = Tokenizer::new(query).tokenize().into_iter().map(|(token, _)| token).collect();
+        MediaQueryList::parse(&tokens).matches(&environment, width, height)
+    }
+
+    fn screen(query: &str, width: f32) -> bool {
+        matches(query, MediaEnvironment::default(), width, 600.0)
+    }
+
+    #[test]
+    fn media_types_with_not_and_only() {
+        let print = MediaEnvironment { media_type: MediaType::Print, ..Default::default() };
+        assert!(screen("", 800.0));
+        assert!(screen("all", 800.0));
+        assert!(screen("screen", 800.0));
+        assert!(!screen("print", 800.0));
+        assert!(matches("print", print, 800.0, 600.0));
+        assert!(screen("only screen", 800.0));
+        assert!(screen("not print", 800.0));
+        assert!(!screen("not screen", 800.0));
+        // `not` sorgunun tamamını olumsuzlar
+        assert!(screen("not screen and (min-width: 1000px)", 800.0));
+        assert!(!screen("not screen and (min-width: 600px)", 800.0));
+        // Virgülle ayrılmış sorgulardan biri eşleşmesi yeterlidir
+        assert!(screen("print, (max-width: 900px)", 800.0));
+        // `only` ve `and` medya tipi olarak kullanılamaz
+        assert!(!screen("only and", 800.0));
+    }
+
+    #[test]
+    fn and_or_and_not_conditions() {
+        assert!(screen("screen and (min-width: 600px) and (max-width: 900px)", 800.0));
+        assert!(!screen("screen and (min-width: 600px) and (max-width: 700px)", 800.0));
+        assert!(screen("(max-width: 500px) or (orientation: landscape)", 800.0));
+        assert!(screen("not (width < 500px)", 800.0));
+        assert!(screen("((min-width: 500px) and (not (max-width: 700px)))", 800.0));
+        // `and` ve `or` parantez olmadan karıştırılamaz, medya tipinden sonra `or` kullanılamaz
+        assert!(!screen("(min-width: 1px) and (min-width: 2px) or (min-width: 3px)", 800.0));
+        assert!(!screen("screen or (min-width: 1px)", 800.0));
+    }
+
+    #[test]
+    fn range_syntax() {
+        assert!(screen("(width >= 800px)", 800.0));
+        assert!(!screen("(width > 800px)", 800.0));
+        assert!(screen("(500px < width)", 800.0));
+        assert!(screen("(400px <= width < 900px)", 800.0));
+        assert!(!screen("(400px <= width < 800px)", 800.0));
+        assert!(screen("(900px > width >= 800px)", 800.0));
+        // İki işleç aynı yönde olmalıdır
+        assert!(!screen("(400px < width > 300px)", 800.0));
+        assert!(screen("(width = 100vw)", 800.0));
+        assert!(screen("(width > 100vmin)", 800.0) && !screen("(width > 100vmin)", 500.0));
+        assert!(screen("(min-width: 40em)", 640.0));
+        assert!(!screen("(min-width: 40em)", 639.0));
+    }
+
+    #[test]
+    fn discrete_features() {
+        let dark = MediaEnvironment { color_scheme: ColorScheme::Dark, ..Default::default() };
+        assert!(screen("(orientation: landscape)", 800.0));
+        assert!(matches("(orientation: portrait)", MediaEnvironment::default(), 400.0, 600.0));
+        assert!(screen("(aspect-ratio: 4/3)", 800.0));
+        assert!(screen("(min-aspect-ratio: 16/9)", 1200.0));
+        assert!(!screen("(prefers-color-scheme: dark)", 800.0));
+        assert!(matches("(PREFERS-COLOR-SCHEME: DARK)", dark, 800.0, 600.0));
+        assert!(screen("(color)", 800.0));
+        assert!(!screen("(monochrome)", 800.0));
+    }
+
+    #[test]
+    fn unknown_features_and_invalid_queries_never_match() {
+        assert!(!screen("(hover: hover)", 800.0));
+        assert!(!screen("not (hover: hover) and (min-width: 1px)", 800.0));
+        assert!(!screen("(min-width: 100%)", 800.0));
+        assert!(!screen("(min-width: 10px 20px)", 800.0));
+        assert!(!screen("tv", 800.0));
+        assert!(!screen("(width)", 0.0));
+        // Geçersiz sorgu yalnızca kendisini `not all` yapar
+        assert!(screen("(min-width: ), screen", 800.0));
+    }
+}
@@ -1,3 +1,10 @@
 pub mod enums;
 pub mod structs;
-pub mod parser;
\ No newline at end of file
+pub mod parser;
+pub mod color;
+pub mod math;
+pub mod media;
+pub mod supports;
+pub mod font_face;
+pub mod shorthand;
+pub mod tokenizer;
\ No newline at end of file
@@ -1,246 +1,899 @@
 //! CSS parser module
 //!
 //! This module provides functionality for parsing CSS strings into a stylesheet.
+//! Both selectors and values are parsed from the token stream produced by the tokenizer.
 
-use super::enums::{Value, Selector, Color, Unit, Display};
-use super::structs::{StyleSheet, Rule, Declaration};
-use crate::css::enums::{SelectorType};
+use super::enums::{Value, Selector, Unit, Display, Origin, Token, SelectorType};
+use super::structs::{StyleSheet, Rule, Declaration, ParseWarning, FontFace};
+use super::tokenizer::{Tokenizer, PositionedToken, preprocess};
+use super::shorthand;
+use super::media::MediaQueryList;
+use super::supports::evaluate_supports;
+use super::font_face;
+use crate::resource::structs::{ResourceLoader, resolve_url};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use super::math::{is_math_function, parse_math_function};
+use super::color::{named_color, hex_color, is_color_function, parse_color_function};
+use crate::style::structs::{is_supported_property, is_valid_value};
 
 /// CSS parser that converts CSS strings into a stylesheet
 pub struct Parser {
-    pos: usize,
-    input: String,
+    tokens: Vec<PositionedToken>,
+    index: usize,
+    input: Rc<str>,
+    pub warnings: Vec<ParseWarning>,
+    loader: Option<Rc<dyn ResourceLoader>>, // @import kaynaklarını yükler
+    location: Option<String>, // Göreli @import adresleri bu konuma göre çözülür
+    import_chain: Vec<String>, // Döngüsel @import'ları yakalamak için yüklenmekte olan sayfalar
+    layer: Option<String>, // İçinde bulunulan @layer katmanının tam adı
+    layers: Vec<String>, // Bildirim sırasına göre katman adları
+    font_faces: Vec<FontFace>, // @font-face kuralları
 }
-fn valid_identifier(c: char) -> bool {
-    matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_')
+
+// Adsız katmanlara benzersiz ad vermek için sayaç
+static ANONYMOUS_LAYERS: AtomicUsize = AtomicUsize::new(0);
+
+type ParseResult<T> = Result<T, String>;
+
+impl AsRef<Token> for Token {
+    fn as_ref(&self) -> &Token {
+        self
+    }
+}
+
+impl AsRef<Token> for PositionedToken {
+    fn as_ref(&self) -> &Token {
+        &self.0
+    }
+}
+
+// Açılış token'ına karşılık gelen kapanış token'ını döndürür
+fn closing_token(token: &Token) -> Option<Token> {
+    match token {
+        Token::OpenParen | Token::Function(_) => Some(Token::CloseParen),
+        Token::OpenSquare => Some(Token::CloseSquare),
+        Token::OpenCurly => Some(Token::CloseCurly),
+        _ => None,
+    }
+}
+
+// Baştaki ve sondaki boşluk token'larını kırpar
+pub fn trim_whitespace<T: AsRef<Token>>(tokens: &[T]) -> &[T] {
+    let start = tokens.iter().position(|t| *t.as_ref() != Token::Whitespace).unwrap_or(tokens.len());
+    let end = tokens.iter().rposition(|t| *t.as_ref() != Token::Whitespace).map_or(start, |i| i + 1);
+    &tokens[start..end]
 }
-fn is_color_value(value: &str) -> bool {
-    let color_names = vec!["red", "green", "blue", "yellow", "purple", "orange", "pink", "brown", "gray", "black", "white", "transparent"];
-    for color_name in color_names {
-        if value.starts_with(color_name) {
-            return true;
+
+// Token listesini, iç içe bloklar dışındaki ayraçlardan böler
+pub fn split_top_level<'t, T: AsRef<Token>>(tokens: &'t [T], separator: &Token) -> Vec<&'t [T]> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        let token = token.as_ref();
+        if closing_token(token).is_some() {
+            depth += 1;
+        } else if matches!(token, Token::CloseParen | Token::CloseSquare | Token::CloseCurly) {
+            depth -= 1;
+        } else if depth == 0 && token == separator {
+            parts.push(&tokens[start..i]);
+            start = i + 1;
         }
     }
-    value.starts_with("rgb(") || value.starts_with("rgba(") || value.starts_with("hsl(") || value.starts_with("hsla(")
+    parts.push(&tokens[start..]);
+    parts
 }
-fn is_length_value(value: &str) -> bool {
-    value.ends_with("px") || value.ends_with("em") || value.ends_with("rem") || value.ends_with("vh") || value.ends_with("vw") || value.ends_with("vmin") || value.ends_with("vmax")
+
+// Token listesini yeniden CSS metnine çevirir
+pub fn serialize_tokens<T: AsRef<Token>>(tokens: &[T]) -> String {
+    tokens.iter().map(|t| t.as_ref().to_string()).collect()
 }
+
 impl Parser {
     pub fn new(input: String) -> Parser {
-        Parser { pos: 0, input }
+        // Uyarı konumları token konumlarıyla aynı, ön işlenmiş metin üzerinden hesaplanır
+        let input = preprocess(&input);
+        let tokens = Tokenizer::new(&input).tokenize();
+        Parser {
+            tokens,
+            index: 0,
+            input: input.into(),
+            warnings: vec![],
+            loader: None,
+            location: None,
+            import_chain: vec![],
+            layer: None,
+            layers: vec![],
+            font_faces: vec![],
+        }
     }
-    fn next_char(&self) -> char {
-        self.input[self.pos..].chars().next().unwrap()
+
+    /// Creates a parser for a stylesheet loaded from `location`; `@import` rules are fetched through `loader`
+    pub fn with_loader(input: String, location: &str, loader: Rc<dyn ResourceLoader>) -> Parser {
+        Parser {
+            location: Some(location.to_string()),
+            import_chain: vec![location.to_string()],
+            loader: Some(loader),
+            ..Parser::new(input)
+        }
     }
-    fn consume_char(&mut self) -> char {
-        let mut iter = self.input[self.pos..].chars();
-        let cur_char = iter.next().unwrap();
-        self.pos += cur_char.len_utf8();
-        cur_char
+    fn next_token(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _)| token)
     }
-    fn eof(&self) -> bool {
-        self.pos >= self.input.len()
+    fn consume_token(&mut self) -> Option<PositionedToken> {
+        let token = self.tokens.get(self.index).cloned();
+        if token.is_some() {
+            self.index += 1;
+        }
+        token
+    }
+    // Geçerli token'ın kaynak metindeki konumu
+    fn position(&self) -> usize {
+        self.tokens.get(self.index).map_or(self.input.chars().count(), |(_, pos)| *pos)
+    }
+    // Verilen konumdaki hatayı satır/sütun bilgisiyle uyarı listesine ekler
+    fn warn(&mut self, pos: usize, message: String) {
+        let before: String = self.input.chars().take(pos).collect();
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+        self.warnings.push(ParseWarning { message, line, column });
+    }
+    fn consume_whitespace(&mut self) {
+        while self.next_token() == Some(&Token::Whitespace) {
+            self.index += 1;
+        }
+    }
+    // Tek bir bileşen değerini tüketir; açılış token'ıysa eşleşen kapanışa kadar hepsini ekler
+    fn consume_component_value(&mut self, out: &mut Vec<PositionedToken>) {
+        let Some(token) = self.consume_token() else { return };
+        let closing = closing_token(&token.0);
+        out.push(token);
+        if let Some(closing) = closing {
+            while let Some(next) = self.next_token() {
+                if *next == closing {
+                    out.push(self.consume_token().unwrap());
+                    return;
+                }
+                self.consume_component_value(out);
+            }
+            // Dosya sonu açık blokları kendiliğinden kapatır
+            out.push((closing, self.position()));
+        }
+    }
+    // `{` tüketildikten sonra bloğun içeriğini eşleşen `}` karakterine kadar okur
+    fn consume_block_contents(&mut self) -> Vec<PositionedToken> {
+        let mut contents = vec![];
+        while let Some(token) = self.next_token() {
+            if *token == Token::CloseCurly {
+                self.index += 1;
+                break;
+            }
+            self.consume_component_value(&mut contents);
+        }
+        contents
     }
-    fn parse_selector(&mut self) -> SelectorType {
-        let mut selector = SelectorType { tag_name: None, id: None, class: vec![] };
 
-        // İlk olarak etiket adı veya evrensel seçiciyi kontrol et
-        if !self.eof() && (valid_identifier(self.next_char()) || self.next_char() == '*') {
-            selector.tag_name = Some(self.parse_identifier());
+    pub fn parse(&mut self) -> StyleSheet {
+        let rules = self.parse_rule_list();
+        StyleSheet {
+            rules,
+            origin: Origin::Author,
+            layers: std::mem::take(&mut self.layers),
+            font_faces: std::mem::take(&mut self.font_faces),
         }
+    }
 
-        // Ardından ID ve sınıf seçicilerini döngüde kontrol et
+    // Kural listesini girdinin sonuna kadar okur; at-kuralları içindeki kurallar düzleştirilir
+    fn parse_rule_list(&mut self) -> Vec<Rule> {
+        let mut rules = vec![];
+        // @import yalnızca diğer kurallardan (@charset ve @layer bildirimleri hariç) önce gelebilir
+        let mut imports_allowed = true;
         loop {
-            if self.eof() {
-                break;
-            }
-            match self.next_char() {
-                '#' => {
-                    self.consume_char();
-                    selector.id = Some(self.parse_identifier());
+            self.consume_whitespace();
+            match self.next_token() {
+                None => break,
+                // HTML yorum işaretleri üst düzeyde yok sayılır
+                Some(Token::Cdo) | Some(Token::Cdc) => self.index += 1,
+                Some(Token::AtKeyword(name)) => {
+                    let name = name.to_lowercase();
+                    rules.extend(self.parse_at_rule(imports_allowed));
+                    // Bloklu @layer kuralları da bir kural sayılır; yalnızca `;` ile biten bildirim biçimi izinlidir
+                    let is_statement = self.index > 0 && self.tokens[self.index - 1].0 == Token::Semicolon;
+                    imports_allowed &= name == "import" || name == "charset" || name == "layer" && is_statement;
                 }
-                '.' => {
-                    self.consume_char();
-                    selector.class.push(self.parse_identifier());
+                Some(_) => {
+                    imports_allowed = false;
+                    if let Some(rule) = self.parse_qualified_rule() {
+                        rules.push(rule);
+                    }
                 }
-                // Eğer hala bir tag_name ayarlanmadıysa ve geçerli bir tanımlayıcıysa
-                c if selector.tag_name.is_none() && valid_identifier(c) => {
-                    selector.tag_name = Some(self.parse_identifier());
+            }
+        }
+        rules
+    }
+
+    // At-kuralının önsözünü ve (varsa) bloğunu okur; önsöz `;` veya `{` ile biter
+    fn consume_at_rule(&mut self) -> (Vec<PositionedToken>, Option<Vec<PositionedToken>>) {
+        let mut prelude = vec![];
+        while let Some(token) = self.next_token() {
+            match token {
+                Token::Semicolon => {
+                    self.index += 1;
+                    return (prelude, None);
                 }
-                _ => {
-                    break; // Diğer karakterlerde döngüyü sonlandır (örn: boşluk, {, ,)
+                Token::OpenCurly => {
+                    self.index += 1;
+                    return (prelude, Some(self.consume_block_contents()));
                 }
+                _ => self.consume_component_value(&mut prelude),
             }
         }
-        selector
+        (prelude, None)
     }
-    fn parse_selectors(&mut self) -> Vec<Selector> {
-        let mut selectors = vec![];
-        loop {
-            selectors.push(Selector::Type(self.parse_selector()));
-            self.consume_whitespace();
-            match self.next_char() {
-                ',' => {
-                    self.consume_char();
-                    continue;
+
+    // Bir blok ya da içe aktarılan sayfa içindeki kural listesini ayrı bir ayrıştırıcıyla okur;
+    // uyarılar ve katman adları bu ayrıştırıcıya aktarılır
+    fn parse_nested_rules(&mut self, tokens: Vec<PositionedToken>, input: Rc<str>, location: Option<String>, layer: Option<String>) -> Vec<Rule> {
+        let mut import_chain = self.import_chain.clone();
+        import_chain.extend(location.clone().filter(|location| self.location.as_ref() != Some(location)));
+        let mut nested = Parser {
+            tokens,
+            index: 0,
+            input,
+            warnings: vec![],
+            loader: self.loader.clone(),
+            location,
+            import_chain,
+            layer,
+            layers: std::mem::take(&mut self.layers),
+            font_faces: std::mem::take(&mut self.font_faces),
+        };
+        let rules = nested.parse_rule_list();
+        self.layers = nested.layers;
+        self.font_faces = nested.font_faces;
+        // İçe aktarılan sayfadaki uyarılar, konumları o sayfaya ait olduğundan sayfa adıyla birlikte bildirilir
+        let imported_from = nested.location.filter(|location| self.location.as_ref() != Some(location));
+        self.warnings.extend(nested.warnings.into_iter().map(|mut warning| {
+            if let Some(location) = &imported_from {
+                warning.message = format!("{} (in {})", warning.message, location);
+            }
+            warning
+        }));
+        rules
+    }
+
+    // Katman adını içinde bulunulan katmana göre tam ada çevirir ve bildirim sırasına ekler
+    fn declare_layer(&mut self, name: Option<&str>) -> String {
+        let name = match name {
+            Some(name) => name.to_string(),
+            // Adsız katmanlar dışarıdan başvurulamayan benzersiz bir ad alır
+            None => format!("#{}", ANONYMOUS_LAYERS.fetch_add(1, AtomicOrdering::Relaxed)),
+        };
+        let full_name = match &self.layer {
+            Some(parent) => format!("{}.{}", parent, name),
+            None => name,
+        };
+        // Üst katmanlar alt katmanlardan önce bildirilmiş sayılır (örn: `a.b` için önce `a`)
+        let segments: Vec<&str> = full_name.split('.').collect();
+        for end in 1..=segments.len() {
+            let prefix = segments[..end].join(".");
+            if !self.layers.contains(&prefix) {
+                self.layers.push(prefix);
+            }
+        }
+        full_name
+    }
+
+    fn parse_at_rule(&mut self, imports_allowed: bool) -> Vec<Rule> {
+        let start = self.position();
+        let Some((Token::AtKeyword(name), _)) = self.consume_token() else { return vec![] };
+        let (prelude, block) = self.consume_at_rule();
+        let prelude: Vec<Token> = prelude.into_iter().map(|(token, _)| token).collect();
+        match (name.to_lowercase().as_str(), block) {
+            // @media: içteki kurallar yalnızca sorgu listesi eşleştiğinde uygulanır
+            ("media", Some(block)) => {
+                let media = MediaQueryList::parse(&prelude);
+                let mut rules = self.parse_nested_rules(block, self.input.clone(), self.location.clone(), self.layer.clone());
+                for rule in rules.iter_mut() {
+                    rule.media.insert(0, media.clone());
+                }
+                rules
+            }
+            // @supports: koşul motorun desteklediği özelliklere göre ayrıştırma sırasında değerlendirilir
+            ("supports", Some(block)) => {
+                if evaluate_supports(&prelude) {
+                    self.parse_nested_rules(block, self.input.clone(), self.location.clone(), self.layer.clone())
+                } else {
+                    vec![]
+                }
+            }
+            // @layer a, b;  katman sırasını bildirir
+            ("layer", None) => {
+                match split_top_level(&prelude, &Token::Comma).into_iter().map(parse_layer_name).collect::<ParseResult<Vec<_>>>() {
+                    Ok(names) => names.iter().for_each(|name| { self.declare_layer(Some(name)); }),
+                    Err(message) => self.warn(start, message),
                 }
-                '{' => break,
-                _ => panic!("Unexpected character: {}", self.next_char()),
+                vec![]
+            }
+            // @layer [ad] { ... }  içteki kurallar katmana aittir
+            ("layer", Some(block)) => {
+                let name = if trim_whitespace(&prelude).is_empty() {
+                    None
+                } else {
+                    match parse_layer_name(&prelude) {
+                        Ok(name) => Some(name),
+                        Err(message) => {
+                            self.warn(start, message);
+                            return vec![];
+                        }
+                    }
+                };
+                let layer = self.declare_layer(name.as_deref());
+                self.parse_nested_rules(block, self.input.clone(), self.location.clone(), Some(layer))
+            }
+            ("import", None) => {
+                if !imports_allowed {
+                    self.warn(start, "@import must come before all other rules".to_string());
+                    return vec![];
+                }
+                match self.parse_import(&prelude) {
+                    Ok(rules) => rules,
+                    Err(message) => {
+                        self.warn(start, message);
+                        vec![]
+                    }
+                }
+            }
+            ("font-face", Some(block)) => {
+                self.parse_font_face(start, &block);
+                vec![]
+            }
+            ("charset", None) => vec![],
+            _ => {
+                self.warn(start, format!("Unsupported at-rule '@{}'", name));
+                vec![]
             }
         }
-        selectors.sort_by(|a, b| b.specificity().cmp(&a.specificity()));
-        selectors
     }
-    fn parse_identifier(&mut self) -> String {
-        let mut identifier = String::new();
-        while !self.eof() && valid_identifier(self.next_char()) {
-            identifier.push(self.consume_char());
+
+    // @import url(...) [layer | layer(ad)] [supports(...)] [medya sorguları];
+    fn parse_import(&mut self, prelude: &[Token]) -> ParseResult<Vec<Rule>> {
+        let parts: Vec<&[Token]> = split_top_level(trim_whitespace(prelude), &Token::Whitespace).into_iter()
+            .filter(|part| !part.is_empty())
+            .collect();
+        let (url, mut rest) = match parts.split_first() {
+            Some(([Token::Url(url)] | [Token::String(url)], rest)) => (url.clone(), rest),
+            Some(([Token::Function(function), Token::String(url), Token::CloseParen], rest)) if function.eq_ignore_ascii_case("url") => {
+                (url.clone(), rest)
+            }
+            _ => return Err("Expected a URL after @import".to_string()),
+        };
+
+        let mut layer = None;
+        match rest.first() {
+            Some([Token::Ident(ident)]) if ident.eq_ignore_ascii_case("layer") => {
+                layer = Some(None);
+                rest = &rest[1..];
+            }
+            Some([Token::Function(function), args @ .., Token::CloseParen]) if function.eq_ignore_ascii_case("layer") => {
+                layer = Some(Some(parse_layer_name(args)?));
+                rest = &rest[1..];
+            }
+            _ => {}
+        }
+        if let Some([Token::Function(function), args @ .., Token::CloseParen]) = rest.first()
+            && function.eq_ignore_ascii_case("supports") {
+            // Koşul bir bildirim de olabilir: supports(display: block)
+            let condition = if matches!(trim_whitespace(args).first(), Some(Token::Ident(_))) && args.contains(&Token::Colon) {
+                [vec![Token::OpenParen], args.to_vec(), vec![Token::CloseParen]].concat()
+            } else {
+                args.to_vec()
+            };
+            if !evaluate_supports(&condition) {
+                return Ok(vec![]);
+            }
+            rest = &rest[1..];
         }
-        identifier
+        let media = MediaQueryList::parse(&rest.join(&Token::Whitespace));
+
+        let Some(loader) = self.loader.clone() else {
+            return Err(format!("Cannot load '{}': no resource loader is configured", url));
+        };
+        let location = resolve_url(self.location.as_deref(), &url);
+        if self.import_chain.contains(&location) {
+            return Err(format!("Circular @import of '{}'", location));
+        }
+        let Some(contents) = loader.load(&location) else {
+            return Err(format!("Cannot load '{}'", location));
+        };
+        let contents = preprocess(&String::from_utf8_lossy(&contents));
+        let tokens = Tokenizer::new(&contents).tokenize();
+
+        let layer = layer.map(|name| self.declare_layer(name.as_deref()));
+        let mut rules = self.parse_nested_rules(tokens, contents.into(), Some(location), layer);
+        for rule in rules.iter_mut() {
+            rule.media.insert(0, media.clone());
+        }
+        Ok(rules)
     }
-    fn consume_whitespace(&mut self) {
-        while !self.eof() && self.next_char().is_whitespace() {
-            self.consume_char();
+
+    // @font-face tanımlayıcılarını okur; aile adı ya da kaynağı olmayan kural atlanır
+    fn parse_font_face(&mut self, start: usize, block: &[PositionedToken]) {
+        // Bloğu kapatan `}` token'ının konumu; son tanımlayıcının kaynak metni burada biter
+        let block_end = match self.tokens[..self.index].last() {
+            Some((Token::CloseCurly, pos)) => *pos,
+            _ => self.input.chars().count(),
+        };
+        let mut face = FontFace::default();
+        for tokens in split_top_level(block, &Token::Semicolon) {
+            let tokens = trim_whitespace(tokens);
+            let Some((_, descriptor_start)) = tokens.first() else { continue };
+            let (Token::Ident(name), rest) = (&tokens[0].0, trim_whitespace(&tokens[1..])) else {
+                self.warn(*descriptor_start, format!("Expected a descriptor name, found '{}'", tokens[0].0));
+                continue;
+            };
+            let Some(((Token::Colon, _), value)) = rest.split_first() else {
+                self.warn(*descriptor_start, format!("Expected ':' after '{}'", name));
+                continue;
+            };
+            let value = trim_whitespace(value);
+            let raw = match (value.first(), value.last()) {
+                (Some((_, value_start)), Some((_, last))) => {
+                    let value_end = block.iter().find(|(_, pos)| pos > last).map_or(block_end, |(_, pos)| *pos);
+                    self.input.chars().skip(*value_start).take(value_end - value_start).collect::<String>()
+                }
+                _ => String::new(),
+            };
+            let value: Vec<Token> = value.iter().map(|(token, _)| token.clone()).collect();
+            if let Err(message) = font_face::apply_descriptor(&mut face, name, &value, raw.trim(), self.location.as_deref()) {
+                self.warn(*descriptor_start, message);
+            }
         }
+        if face.family.is_empty() || face.sources.is_empty() {
+            self.warn(start, "@font-face requires 'font-family' and 'src' descriptors".to_string());
+            return;
+        }
+        self.font_faces.push(face);
     }
-    fn parse_declarations(&mut self) -> Vec<Declaration> {
-        let mut declarations = vec![];
+
+    fn parse_qualified_rule(&mut self) -> Option<Rule> {
+        let start = self.position();
+        let mut prelude = vec![];
         loop {
-            self.consume_whitespace();
-            if self.next_char() == '}' {
-                break;
+            match self.next_token() {
+                None => {
+                    self.warn(start, "Unexpected end of input in selector".to_string());
+                    return None;
+                }
+                Some(Token::OpenCurly) => {
+                    self.index += 1;
+                    break;
+                }
+                Some(_) => self.consume_component_value(&mut prelude),
+            }
+        }
+        let block = self.consume_block_contents();
+
+        // Geçersiz seçici listesi tüm kuralı geçersiz kılar
+        let selectors = match parse_selectors(&prelude) {
+            Ok(selectors) => selectors,
+            Err(message) => {
+                self.warn(start, message);
+                return None;
+            }
+        };
+        let declarations = self.parse_declarations(&block);
+        Some(Rule { selectors, declarations, media: vec![], layer: self.layer.clone() })
+    }
+
+    fn parse_declarations(&mut self, block: &[PositionedToken]) -> Vec<Declaration> {
+        let mut declarations = vec![];
+        for tokens in split_top_level(block, &Token::Semicolon) {
+            let tokens = trim_whitespace(tokens);
+            // Boş bildirimleri (örn: `;;`) atla
+            let Some((_, start)) = tokens.first() else { continue };
+            match parse_declaration(tokens) {
+                Ok(expanded) => declarations.extend(expanded),
+                Err(message) => self.warn(*start, message),
             }
-            declarations.push(self.parse_declaration());
-            self.consume_whitespace();
         }
         declarations
     }
-    fn parse_declaration(&mut self) -> Declaration {
-        let property = self.parse_identifier();
-        self.consume_whitespace();
-        assert_eq!(self.consume_char(), ':');
-        self.consume_whitespace();
-        let value = self.parse_value(&property);
-        self.consume_whitespace();
-        assert_eq!(self.consume_char(), ';');
-        Declaration { property, value }
-    }
-    fn parse_value(&mut self, property: &str) -> Value {
-        let mut value = String::new();
-        while !self.eof() && (self.next_char() != ';' && self.next_char() != '}') {
-            value.push(self.consume_char());
-        }
-
-        let trimmed_value = value.trim().to_lowercase();
-
-        if property == "display" {
-            match trimmed_value.as_str() {
-                "block" => return Value::Display(Display::Block),
-                "inline" => return Value::Display(Display::Inline),
-                "none" => return Value::Display(Display::None),
-                _ => {},
-            }
-        }
-
-        if is_color_value(&value) {
-            return Value::Color(self.parse_color(value));
-        }
-        if is_length_value(&value) {
-            let length = self.parse_length_value(value.trim_end_matches(|c: char| !c.is_numeric()).to_string());
-            let unit = self.parse_unit_value(value.trim_start_matches(|c: char| c.is_numeric() || c == '.' || c == '-').to_string());
-            return Value::Length(length, unit);
-        }
-
-        Value::Keyword(value.trim().to_string())
-    }
-    fn parse_color(&mut self, color: String) -> Color {
-        if color.starts_with("rgb(") || color.starts_with("rgba(") {
-            let rgb = self.parse_rgb(color);
-            return Color::RGBA(rgb.0, rgb.1, rgb.2, rgb.3);
-        }
-        if color.starts_with("hsl(") || color.starts_with("hsla(") {
-            let hsl = self.parse_hsl();
-            return Color::HSLA(hsl.0, hsl.1, hsl.2, hsl.3);
-        }
-        match color.trim().to_lowercase().as_str() {
-            "red" => return Color::RGBA(255, 0, 0, 1.0),
-            "green" => return Color::RGBA(0, 128, 0, 1.0),
-            "blue" => return Color::RGBA(0, 0, 255, 1.0),
-            "yellow" => return Color::RGBA(255, 255, 0, 1.0),
-            "purple" => return Color::RGBA(128, 0, 128, 1.0),
-            "orange" => return Color::RGBA(255, 165, 0, 1.0),
-            "pink" => return Color::RGBA(255, 192, 203, 1.0),
-            "brown" => return Color::RGBA(165, 42, 42, 1.0),
-            "gray" => return Color::RGBA(128, 128, 128, 1.0),
-            "black" => return Color::RGBA(0, 0, 0, 1.0),
-            "white" => return Color::RGBA(255, 255, 255, 1.0),
-            "transparent" => return Color::RGBA(0, 0, 0, 0.0),
-            _ => {}
+}
+
+// Katman adı: noktalarla ayrılmış tanımlayıcılar (örn: `base.reset`)
+fn parse_layer_name(tokens: &[Token]) -> ParseResult<String> {
+    let tokens = trim_whitespace(tokens);
+    let mut segments = vec![];
+    for (index, token) in tokens.iter().enumerate() {
+        match (index % 2, token) {
+            (0, Token::Ident(name)) => segments.push(name.clone()),
+            (1, Token::Delim('.')) => {}
+            _ => return Err(format!("Invalid layer name '{}'", serialize_tokens(tokens))),
+        }
+    }
+    if segments.is_empty() || tokens.len().is_multiple_of(2) {
+        return Err(format!("Invalid layer name '{}'", serialize_tokens(tokens)));
+    }
+    Ok(segments.join("."))
+}
+
+pub fn parse_selectors(prelude: &[PositionedToken]) -> ParseResult<Vec<Selector>> {
+    split_top_level(prelude, &Token::Comma).into_iter()
+        .map(|tokens| parse_selector(trim_whitespace(tokens)).map(Selector::Type))
+        .collect()
+}
+
+fn parse_selector(tokens: &[PositionedToken]) -> ParseResult<SelectorType> {
+    let mut selector = SelectorType { tag_name: None, id: None, class: vec![] };
+    let mut tokens = tokens.iter().map(|(token, _)| token).peekable();
+
+    // İlk olarak etiket adı veya evrensel seçiciyi kontrol et
+    match tokens.peek() {
+        None => return Err("Expected a selector".to_string()),
+        Some(Token::Ident(name)) => {
+            selector.tag_name = Some(name.to_lowercase());
+            tokens.next();
         }
-        panic!("Invalid color value: {}", color);
-    }
-    fn parse_rgb(&mut self, color: String) -> (u8, u8, u8, f32) {
-        let color = color.trim_matches(|c: char| !c.is_numeric() && c != '.' && c != ',' && c != ' ');
-        let parts: Vec<&str> = color.split(',').map(|x| x.trim()).collect();
-        
-        if parts.len() == 3 {
-            let r = parts[0].parse::<u8>().unwrap_or(0);
-            let g = parts[1].parse::<u8>().unwrap_or(0);
-            let b = parts[2].parse::<u8>().unwrap_or(0);
-            return (r, g, b, 1.0);
-        } else if parts.len() == 4 {
-            let r = parts[0].parse::<u8>().unwrap_or(0);
-            let g = parts[1].parse::<u8>().unwrap_or(0);
-            let b = parts[2].parse::<u8>().unwrap_or(0);
-            let a = parts[3].parse::<f32>().unwrap_or(1.0);
-            return (r, g, b, a);
-        } else {
-            panic!("Invalid RGB value: {}", color);
-        }
-    }
-    fn parse_length_value(&mut self, length: String) -> f32 {
-        length.parse::<f32>().unwrap_or(0.0)
-    }
-    fn parse_unit_value(&mut self, unit: String) -> Unit {
-        match unit.to_lowercase().as_str() {
-            "px" => Unit::Px,
-            "em" => Unit::Em,
-            "rem" => Unit::Rem,
-            "vh" => Unit::Vh,
-            "vw" => Unit::Vw,
-            "vmin" => Unit::Vmin,
-            "vmax" => Unit::Vmax,
-            _ => Unit::Px,
-        }
-    }
-    fn parse_hsl(&mut self) -> (f32, f32, f32, f32) {
-        let mut hsl = String::new();
-        while !self.eof() && (self.next_char() != ';' && self.next_char() != '}') {
-            hsl.push(self.consume_char());
-        }
-        let hsl = hsl.split(',').map(|x| x.parse::<f32>().unwrap()).collect::<Vec<f32>>();
-        (hsl[0], hsl[1], hsl[2], hsl[3])
-    }
-    fn parse_rule(&mut self) -> Rule {
-        let selectors = self.parse_selectors();
-        self.consume_whitespace();
-        assert_eq!(self.consume_char(), '{');
-        let declarations = self.parse_declarations();
-        self.consume_whitespace();
-        assert_eq!(self.consume_char(), '}');
-        Rule {
-            selectors,
-            declarations,
+        Some(Token::Delim('*')) => {
+            tokens.next(); // Evrensel seçici her etikete uyar
         }
+        _ => {}
     }
-    pub fn parse(&mut self) -> StyleSheet {
-        self.consume_whitespace();
-        let mut rules = vec![];
-        while !self.eof() {
-            rules.push(self.parse_rule());
-            self.consume_whitespace();
+
+    // Ardından ID ve sınıf seçicilerini döngüde kontrol et
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Hash(id, true) => selector.id = Some(id.clone()),
+            Token::Delim('.') => match tokens.next() {
+                Some(Token::Ident(class)) => selector.class.push(class.clone()),
+                _ => return Err("Expected a class name after '.'".to_string()),
+            },
+            Token::Whitespace => return Err("Combinators are not supported in selectors".to_string()),
+            token => return Err(format!("Unexpected token in selector: '{}'", token)),
+        }
+    }
+    Ok(selector)
+}
+
+// Kısaltma özellikleri birden fazla uzun biçim bildirimine açılır
+pub fn parse_declaration(tokens: &[PositionedToken]) -> ParseResult<Vec<Declaration>> {
+    let tokens: Vec<Token> = tokens.iter().map(|(token, _)| token.clone()).collect();
+    let property = match tokens.first() {
+        // Özel özellik (`--ad`) adları büyük/küçük harf duyarlıdır
+        Some(Token::Ident(name)) if name.starts_with("--") => name.clone(),
+        Some(Token::Ident(name)) => name.to_lowercase(),
+        Some(token) => return Err(format!("Expected a property name, found '{}'", token)),
+        None => return Err("Expected a property name".to_string()),
+    };
+    let rest = trim_whitespace(&tokens[1..]);
+    if rest.first() != Some(&Token::Colon) {
+        return Err(format!("Expected ':' after property '{}'", property));
+    }
+    let mut value_tokens = trim_whitespace(&rest[1..]);
+
+    // Sondaki `!important` işaretini ayır
+    let mut important = false;
+    if let Some(Token::Ident(keyword)) = value_tokens.last()
+        && keyword.eq_ignore_ascii_case("important") {
+        let without_keyword = trim_whitespace(&value_tokens[..value_tokens.len() - 1]);
+        if without_keyword.last() == Some(&Token::Delim('!')) {
+            important = true;
+            value_tokens = trim_whitespace(&without_keyword[..without_keyword.len() - 1]);
+        }
+    }
+
+    let is_custom_property = property.starts_with("--");
+    if value_tokens.is_empty() && !is_custom_property {
+        return Err(format!("Missing value for property '{}'", property));
+    }
+    if value_tokens.iter().any(|t| matches!(t, Token::BadString | Token::BadUrl | Token::OpenCurly)) {
+        return Err(format!("Invalid value for property '{}'", property));
+    }
+
+    // Özel özellikler ve `var()` içeren değerler ayrıştırılmadan saklanır; hesaplanan değer aşamasında çözülür
+    let has_var = value_tokens.iter().any(|t| matches!(t, Token::Function(name) if name.eq_ignore_ascii_case("var")));
+    if is_custom_property || has_var {
+        let value = match value_tokens {
+            [Token::Ident(keyword)] if matches!(keyword.to_lowercase().as_str(), "inherit" | "initial" | "unset" | "revert") => {
+                Value::Keyword(keyword.to_lowercase())
+            }
+            _ => Value::Tokens(value_tokens.to_vec()),
+        };
+        // `var()` içeren kısaltma yine de uzun biçimlerine açılır; her biri kademede kısaltmanın kendi
+        // kökeni, özgüllüğü ve sırasıyla yarışır, değeri ise yerine koyma sonrasında kısaltmadan çıkarılır
+        if !is_custom_property
+            && let Value::Tokens(tokens) = &value
+            && let Some(longhands) = shorthand::longhands(&property) {
+            return Ok(longhands.into_iter()
+                .map(|longhand| Declaration {
+                    property: longhand,
+                    value: Value::PendingSubstitution(property.clone(), tokens.clone()),
+                    important,
+                })
+                .collect());
+        }
+        return Ok(vec![Declaration { property, value, important }]);
+    }
+    if let Some(expanded) = shorthand::expand(&property, value_tokens) {
+        return Ok(expanded?.into_iter()
+            .map(|(property, value)| Declaration { property, value, important })
+            .collect());
+    }
+    // Özellik ve değer motorun özellik kaydına göre doğrulanır; geçersiz bildirim atılır
+    if !is_supported_property(&property) {
+        return Err(format!("Unknown property '{}'", property));
+    }
+    let value = parse_value(&property, value_tokens)?;
+    if !is_valid_value(&property, &value) {
+        return Err(format!("Invalid value '{}' for property '{}'", serialize_tokens(value_tokens), property));
+    }
+    Ok(vec![Declaration { property, value, important }])
+}
+
+// Birimsiz sayıları anlamlı bir değer olarak kabul eden özellikler
+fn accepts_number(property: &str) -> bool {
+    matches!(property, "line-height" | "font-weight" | "opacity" | "z-index" | "order" | "flex-grow" | "flex-shrink")
+}
+
+/// Parses the tokens of a declaration value into a `Value`
+pub fn parse_value(property: &str, tokens: &[Token]) -> ParseResult<Value> {
+    let tokens = trim_whitespace(tokens);
+    match tokens {
+        [Token::Ident(ident)] => {
+            if property == "display" {
+                match ident.to_lowercase().as_str() {
+                    "block" => return Ok(Value::Display(Display::Block)),
+                    "inline" => return Ok(Value::Display(Display::Inline)),
+                    "inline-block" => return Ok(Value::Display(Display::InlineBlock)),
+                    "flow-root" => return Ok(Value::Display(Display::FlowRoot)),
+                    "contents" => return Ok(Value::Display(Display::Contents)),
+                    "none" => return Ok(Value::Display(Display::None)),
+                    _ => {},
+                }
+            }
+            if let Some(color) = named_color(ident) {
+                return Ok(Value::Color(color));
+            }
+            // Anahtar kelimeler ASCII büyük/küçük harf duyarsızdır ve küçük harfle saklanır; yazı tipi adları korunur
+            if property == "font-family" {
+                return Ok(Value::Keyword(ident.clone()));
+            }
+            Ok(Value::Keyword(ident.to_ascii_lowercase()))
+        }
+        [Token::Hash(digits, _)] => Ok(Value::Color(hex_color(digits)?)),
+        [Token::Dimension(length, unit)] => match parse_unit(unit) {
+            Some(unit) => Ok(Value::Length(*length, unit)),
+            None => Err(format!("Unknown unit '{}'", unit)),
+        },
+        [Token::Percentage(percentage)] => Ok(Value::Length(*percentage, Unit::Percent)),
+        // Sıfır uzunluklarda birim yazılmayabilir
+        [Token::Number(number)] if *number == 0.0 && !accepts_number(property) => Ok(Value::Length(0.0, Unit::Px)),
+        [Token::Number(number)] => Ok(Value::Number(*number)),
+        [Token::Function(name), args @ .., Token::CloseParen] if is_math_function(name) => {
+            Ok(Value::Math(parse_math_function(name, args)?))
+        }
+        [Token::Function(name), args @ .., Token::CloseParen] if is_color_function(name) => {
+            Ok(Value::Color(parse_color_function(name, args)?))
+        }
+        _ => {
+            // Boşlukla ayrılmış çok bileşenli değerler listeye dönüşür; virgüllü değerler anahtar kelime olarak kalır
+            let components: Vec<&[Token]> = split_top_level(tokens, &Token::Whitespace).into_iter()
+                .filter(|component| !component.is_empty())
+                .collect();
+            if components.len() > 1 && property != "font-family" && split_top_level(tokens, &Token::Comma).len() == 1 {
+                let values = components.into_iter()
+                    .map(|component| parse_value(property, component))
+                    .collect::<ParseResult<Vec<Value>>>()?;
+                return Ok(Value::List(values));
+            }
+            Ok(Value::Keyword(serialize_tokens(tokens)))
+        }
+    }
+}
+
+pub fn parse_unit(unit: &str) -> Option<Unit> {
+    match unit.to_lowercase().as_str() {
+        "px" => Some(Unit::Px),
+        "em" => Some(Unit::Em),
+        "rem" => Some(Unit::Rem),
+        "vh" => Some(Unit::Vh),
+        "vw" => Some(Unit::Vw),
+        "vmin" => Some(Unit::Vmin),
+        "vmax" => Some(Unit::Vmax),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use crate::css::media::MediaEnvironment;
+
+    // Stil sayfasını ayrıştırır; her kuralın bildirdiği özellik adlarını ve uyarıların konumlarını döndürür
+    fn parse(css: &str) -> (Vec<Vec<String>>, Vec<(usize, usize)>) {
+        let mut parser = Parser::new(css.to_string());
+        let stylesheet = parser.parse();
+        let rules = stylesheet.rules.iter()
+            .map(|rule| rule.declarations.iter().map(|declaration| declaration.property.clone()).collect())
+            .collect();
+        let warnings = parser.warnings.iter().map(|warning| (warning.line, warning.column)).collect();
+        (rules, warnings)
+    }
+
+    #[test]
+    fn invalid_declarations_are_dropped_up_to_the_next_semicolon() {
+        let (rules, warnings) = parse("div { color: red; width: ; height 10px; margin-top: 1px; }");
+        assert_eq!(rules, vec![vec!["color", "margin-top"]]);
+        assert_eq!(warnings, vec![(1, 19), (1, 28)]);
+        // Parantez içindeki `;` bildirimi bitirmez
+        let (rules, warnings) = parse("div { width: calc(1px; 2px); color: red }");
+        assert_eq!(rules, vec![vec!["color"]]);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn invalid_rules_are_dropped_up_to_their_closing_brace() {
+        let (rules, warnings) = parse("div > p { color: red; a { b: c } } p { color: blue } !x { } span { width: 1px }");
+        assert_eq!(rules, vec![vec!["color"], vec!["width"]]);
+        assert_eq!(warnings, vec![(1, 1), (1, 54)]);
+        let (rules, warnings) = parse("@unknown foo { p { color: red } } p { color: blue }");
+        assert_eq!(rules, vec![vec!["color"]]);
+        assert_eq!(warnings, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn unclosed_blocks_strings_and_comments() {
+        // Girdinin sonu açık blokları kapatır
+        let (rules, warnings) = parse("div { color: red; margin: 1px");
+        assert_eq!(rules, vec![vec!["color", "margin-top", "margin-right", "margin-bottom", "margin-left"]]);
+        assert!(warnings.is_empty());
+        // Satır sonunda biten dizge yalnızca kendi bildirimini geçersiz kılar
+        let (rules, warnings) = parse("div { font-family: \"abc\n; color: red } p { color: blue }");
+        assert_eq!(rules, vec![vec!["color"], vec!["color"]]);
+        assert_eq!(warnings, vec![(1, 7)]);
+        let (rules, _) = parse("div { color: red } /* p { color: blue }");
+        assert_eq!(rules, vec![vec!["color"]]);
+        let (rules, warnings) = parse("div { color: red } p");
+        assert_eq!(rules, vec![vec!["color"]]);
+        assert_eq!(warnings, vec![(1, 20)]);
+    }
+
+    #[test]
+    fn warning_positions_count_lines_and_columns() {
+        let (_, warnings) = parse("div { color: red;\n  width: ; }\np{ x }");
+        assert_eq!(warnings, vec![(2, 3), (3, 4)]);
+        // CRLF, CR ve FF tek bir satır sonu sayılır
+        let (_, warnings) = parse("div { color: red;\r\n  width: ; }\r\np{ x }");
+        assert_eq!(warnings, vec![(2, 3), (3, 4)]);
+        let (_, warnings) = parse("div {\r\r  width: ; }\u{c}p{ x }");
+        assert_eq!(warnings, vec![(3, 3), (4, 4)]);
+        let (_, warnings) = parse("/* ü */ p { x }");
+        assert_eq!(warnings, vec![(1, 13)]);
+    }
+
+    #[test]
+    fn values_are_checked_against_their_property() {
+        let (rules, warnings) = parse("div { color: red; color: redish; width: bogus; width: red; margin-top: 5; float: left }");
+        assert_eq!(rules, vec![vec!["color"]]);
+        assert_eq!(warnings.len(), 5);
+        let (rules, warnings) = parse("div { width: AUTO; line-height: 1.5; margin-top: 0; text-decoration: underline red; \
+                                       border-top-color: currentColor; height: calc(50% - 2px); color: inherit; --x: bogus }");
+        assert_eq!(rules[0].len(), 8);
+        assert!(warnings.is_empty());
+        // Tür uymayan matematik ifadeleri ve iç içe CSS genel anahtar kelimeleri geçersizdir
+        let (rules, warnings) = parse("div { width: calc(2 * 3); text-decoration: underline inherit; width: calc(1px / 0) }");
+        assert_eq!(rules, vec![Vec::<String>::new()]);
+        assert_eq!(warnings.len(), 3);
+    }
+
+    #[test]
+    fn repeated_important_makes_the_declaration_invalid() {
+        let (rules, warnings) = parse("div { margin: 1px !important !important; width: 1px !important !important; color: red !important }");
+        assert_eq!(rules, vec![vec!["color"]]);
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn keywords_are_lowercased_except_font_families() {
+        let keyword = |property, css| match parse_value(property, &Tokenizer::new(css).tokenize().into_iter().map(|(t, _)| t).collect::<Vec<_>>()) {
+            Ok(Value::Keyword(keyword)) => keyword,
+            Ok(Value::List(values)) => values.iter().map(|value| match value {
+                Value::Keyword(keyword) => keyword.clone(),
+                _ => "?".to_string(),
+            }).collect::<Vec<_>>().join(" "),
+            _ => "?".to_string(),
+        };
+        assert_eq!(keyword("text-align", "CENTER"), "center");
+        assert_eq!(keyword("border-top-color", "currentColor"), "currentcolor");
+        assert_eq!(keyword("text-decoration", "Underline Overline"), "underline overline");
+        assert_eq!(keyword("font-family", "Arial"), "Arial");
+    }
+
+    // Sayfaları bellekten yükler; verilen konumların dışındakiler yüklenemez
+    struct MemoryLoader(HashMap<&'static str, &'static str>);
+
+    impl ResourceLoader for MemoryLoader {
+        fn load(&self, location: &str) -> Option<Vec<u8>> {
+            self.0.get(location).map(|contents| contents.as_bytes().to_vec())
         }
-        StyleSheet { rules }
+    }
+
+    // `/site/main.css` sayfasını verilen sayfalarla birlikte ayrıştırır
+    fn parse_with_imports(main: &str, files: &[(&'static str, &'static str)]) -> (StyleSheet, Vec<String>) {
+        let loader = Rc::new(MemoryLoader(files.iter().copied().collect()));
+        let mut parser = Parser::with_loader(main.to_string(), "/site/main.css", loader);
+        let stylesheet = parser.parse();
+        (stylesheet, parser.warnings.into_iter().map(|warning| warning.message).collect())
+    }
+
+    // Her kuralın ilk bildirdiği özellik ve katmanı
+    fn rule_summary(stylesheet: &StyleSheet) -> Vec<(String, Option<String>)> {
+        stylesheet.rules.iter()
+            .map(|rule| (rule.declarations[0].property.clone(), rule.layer.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn imports_resolve_relative_urls_against_the_importing_sheet() {
+        let (stylesheet, warnings) = parse_with_imports("@import \"css/a.css\"; @import url(/shared/c.css); p { color: red }", &[
+            ("/site/css/a.css", "@import '../b.css'; a { width: 1px }"),
+            ("/site/b.css", "b { height: 1px }"),
+            ("/shared/c.css", "c { margin-top: 1px }"),
+        ]);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        let properties: Vec<String> = rule_summary(&stylesheet).into_iter().map(|(property, _)| property).collect();
+        assert_eq!(properties, vec!["height", "width", "margin-top", "color"]);
+
+        let (stylesheet, warnings) = parse_with_imports("@import 'missing.css'; p { color: red } @import 'css/a.css';", &[]);
+        assert_eq!(stylesheet.rules.len(), 1);
+        assert_eq!(warnings, vec!["Cannot load '/site/missing.css'", "@import must come before all other rules"]);
+    }
+
+    #[test]
+    fn circular_imports_are_loaded_once() {
+        let (stylesheet, warnings) = parse_with_imports("@import 'a.css'; p { color: red }", &[
+            ("/site/a.css", "@import 'b.css'; a { width: 1px }"),
+            ("/site/b.css", "@import './a.css'; @import 'main.css'; b { height: 1px }"),
+        ]);
+        let properties: Vec<String> = rule_summary(&stylesheet).into_iter().map(|(property, _)| property).collect();
+        assert_eq!(properties, vec!["height", "width", "color"]);
+        assert_eq!(warnings, vec![
+            "Circular @import of '/site/a.css' (in /site/b.css) (in /site/a.css)",
+            "Circular @import of '/site/main.css' (in /site/b.css) (in /site/a.css)",
+        ]);
+    }
+
+    #[test]
+    fn import_conditions_and_layers() {
+        let files = [("/site/a.css", "a { width: 1px }")];
+        let (stylesheet, _) = parse_with_imports("@import 'a.css' layer(base.reset); @import 'a.css' layer; @import 'a.css';", &files);
+        let layers: Vec<Option<String>> = rule_summary(&stylesheet).into_iter().map(|(_, layer)| layer).collect();
+        assert_eq!(layers[0].as_deref(), Some("base.reset"));
+        assert!(layers[1].as_ref().is_some_and(|layer| layer.starts_with('#')));
+        assert_eq!(layers[2], None);
+        assert_eq!(stylesheet.layers[..2], ["base", "base.reset"]);
+
+        // Desteklenmeyen koşulla içe aktarılan sayfa hiç yüklenmez
+        let (stylesheet, warnings) = parse_with_imports("@import 'a.css' supports(display: grid); @import 'missing.css' supports(not (display: block));", &files);
+        assert!(stylesheet.rules.is_empty() && warnings.is_empty());
+        let (stylesheet, _) = parse_with_imports("@import 'a.css' layer(x) supports(display: block) print, (min-width: 100px);", &files);
+        assert_eq!(rule_summary(&stylesheet), vec![("width".to_string(), Some("x".to_string()))]);
+        let media = &stylesheet.rules[0].media;
+        assert_eq!(media.len(), 1);
+        assert!(media[0].matches(&MediaEnvironment::default(), 800.0, 600.0));
+        assert!(!media[0].matches(&MediaEnvironment::default(), 50.0, 600.0));
+    }
+
+    #[test]
+    fn layers_are_declared_in_order_of_first_appearance() {
+        let stylesheet = Parser::new("@layer b, a.x; @layer a { @layer y { p { width: 1px } } p { height: 1px } } @layer { p { color: red } } @layer b.z { p { margin-top: 0 } }".to_string()).parse();
+        assert_eq!(stylesheet.layers[..4], ["b", "a", "a.x", "a.y"]);
+        assert!(stylesheet.layers[4].starts_with('#'));
+        assert_eq!(stylesheet.layers[5], "b.z");
+        let layers: Vec<Option<String>> = rule_summary(&stylesheet).into_iter().map(|(_, layer)| layer).collect();
+        assert_eq!(layers, vec![Some("a.y".to_string()), Some("a".to_string()), Some(stylesheet.layers[4].clone()), Some("b.z".to_string())]);
     }
 }
@@ -0,0 +1,298 @@
+//! CSS shorthand expansion module
+//!
+//! This module expands shorthand properties (`margin`, `border`, `font`, ...) into
+//! their longhand properties at parse time.
+
+use super::enums::{Value, Token, Unit};
+use super::parser::{parse_value, serialize_tokens, split_top_level, trim_whitespace};
+use crate::style::structs::is_valid_value;
+
+type ParseResult<T> = Result<T, String>;
+
+const SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+
+const FONT_SIZE_KEYWORDS: [&str; 10] = ["xx-small", "x-small", "small", "medium", "large", "x-large", "xx-large", "xxx-large", "larger", "smaller"];
+
+const BORDER_STYLES: [&str; 10] = ["none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset"];
+
+/// Returns the longhand properties a shorthand expands to, or `None` if the property is not a shorthand
+pub fn longhands(property: &str) -> Option<Vec<String>> {
+    let sides = |pattern: &str| SIDES.iter().map(|side| pattern.replace("{}", side)).collect::<Vec<_>>();
+    let longhands = match property {
+        "margin" => sides("margin-{}"),
+        "padding" => sides("padding-{}"),
+        "border-width" => sides("border-{}-width"),
+        "border-style" => sides("border-{}-style"),
+        "border-color" => sides("border-{}-color"),
+        "border" => [sides("border-{}-width"), sides("border-{}-style"), sides("border-{}-color")].concat(),
+        "border-top" | "border-right" | "border-bottom" | "border-left" => {
+            vec![format!("{}-width", property), format!("{}-style", property), format!("{}-color", property)]
+        }
+        "font" => ["font-style", "font-variant", "font-weight", "font-size", "line-height", "font-family"]
+            .iter().map(|s| s.to_string()).collect(),
+        "background" => ["background-color", "background-image", "background-repeat", "background-position"]
+            .iter().map(|s| s.to_string()).collect(),
+        _ => return None,
+    };
+    Some(longhands)
+}
+
+/// Expands a shorthand declaration into `(longhand, value)` pairs.
+/// Returns `None` when the property is not a shorthand.
+pub fn expand(property: &str, tokens: &[Token]) -> Option<ParseResult<Vec<(String, Value)>>> {
+    let longhands = longhands(property)?;
+    let tokens = trim_whitespace(tokens);
+
+    // CSS genel anahtar kelimeleri tüm uzun biçimlere aynen uygulanır
+    if let [Token::Ident(keyword)] = tokens
+        && matches!(keyword.to_lowercase().as_str(), "inherit" | "initial" | "unset" | "revert") {
+        return Some(Ok(longhands.into_iter().map(|name| (name, Value::Keyword(keyword.to_lowercase()))).collect()));
+    }
+
+    let expanded = match property {
+        "margin" | "padding" | "border-width" | "border-style" | "border-color" => expand_box(&longhands, tokens),
+        "border" | "border-top" | "border-right" | "border-bottom" | "border-left" => expand_border(&longhands, tokens),
+        "font" => expand_font(tokens),
+        "background" => expand_background(tokens),
+        _ => unreachable!(),
+    };
+    // Her bileşen kendi uzun biçiminin kabul ettiği bir değer olmalıdır; aksi halde kısaltmanın tamamı geçersizdir
+    Some(expanded.and_then(|expanded| match expanded.iter().all(|(longhand, value)| is_valid_value(longhand, value)) {
+        true => Ok(expanded),
+        false => Err(format!("Invalid value '{}' for property '{}'", serialize_tokens(tokens), property)),
+    }))
+}
+
+// Değeri boşluklarla ayrılmış bileşenlere böler (fonksiyon argümanları bölünmez)
+fn components(tokens: &[Token]) -> Vec<&[Token]> {
+    split_top_level(tokens, &Token::Whitespace).into_iter()
+        .filter(|component| !component.is_empty())
+        .collect()
+}
+
+// 1-4 değerli kutu kısaltmaları: üst, sağ, alt, sol
+fn expand_box(longhands: &[String], tokens: &[Token]) -> ParseResult<Vec<(String, Value)>> {
+    let values = components(tokens).into_iter()
+        .map(|component| parse_value(&longhands[0], component))
+        .collect::<ParseResult<Vec<Value>>>()?;
+    let [top, right, bottom, left] = match values.as_slice() {
+        [all] => [all, all, all, all],
+        [vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+        [top, horizontal, bottom] => [top, horizontal, bottom, horizontal],
+        [top, right, bottom, left] => [top, right, bottom, left],
+        _ => return Err(format!("Expected 1 to 4 values, found {}", values.len())),
+    };
+    Ok(longhands.iter().cloned().zip([top, right, bottom, left].into_iter().cloned()).collect())
+}
+
+fn is_border_style(value: &Value) -> bool {
+    matches!(value, Value::Keyword(k) if BORDER_STYLES.contains(&k.to_lowercase().as_str()))
+}
+
+fn is_border_width(value: &Value) -> bool {
+    match value {
+        Value::Length(_, unit) => *unit != Unit::Percent,
+        Value::Keyword(k) => matches!(k.to_lowercase().as_str(), "thin" | "medium" | "thick"),
+        _ => false,
+    }
+}
+
+// <length-percentage>, <absolute-size> ya da <relative-size>; negatif boyutlar geçersizdir
+fn is_font_size(value: &Value) -> bool {
+    match value {
+        Value::Length(size, _) => *size >= 0.0,
+        Value::Math(_) => true,
+        Value::Keyword(k) => FONT_SIZE_KEYWORDS.contains(&k.to_lowercase().as_str()),
+        _ => false,
+    }
+}
+
+fn is_color(value: &Value) -> bool {
+    matches!(value, Value::Color(_)) || matches!(value, Value::Keyword(k) if k.eq_ignore_ascii_case("currentcolor"))
+}
+
+// `border` ve `border-<taraf>`: genişlik, stil ve renk herhangi bir sırada, her biri en fazla bir kez
+fn expand_border(longhands: &[String], tokens: &[Token]) -> ParseResult<Vec<(String, Value)>> {
+    let (mut width, mut style, mut color) = (None, None, None);
+    for component in components(tokens) {
+        let value = parse_value("border-width", component)?;
+        if width.is_none() && is_border_width(&value) {
+            width = Some(value);
+        } else if style.is_none() && is_border_style(&value) {
+            style = Some(value);
+        } else if color.is_none() && is_color(&value) {
+            color = Some(value);
+        } else {
+            return Err(format!("Invalid border value: {}", serialize_tokens(component)));
+        }
+    }
+    // Belirtilmeyen bileşenler başlangıç değerlerine döner
+    let initial = || Value::Keyword("initial".to_string());
+    let width = width.unwrap_or_else(initial);
+    let style = style.unwrap_or_else(initial);
+    let color = color.unwrap_or_else(initial);
+    Ok(longhands.iter().map(|name| {
+        let value = if name.ends_with("-width") {
+            width.clone()
+        } else if name.ends_with("-style") {
+            style.clone()
+        } else {
+            color.clone()
+        };
+        (name.clone(), value)
+    }).collect())
+}
+
+// `font`: [stil] [varyant] [kalınlık] boyut[/satır-yüksekliği] aile
+fn expand_font(tokens: &[Token]) -> ParseResult<Vec<(String, Value)>> {
+    let parts = components(tokens);
+    let initial = || Value::Keyword("initial".to_string());
+    let (mut style, mut variant, mut weight) = (None, None, None);
+
+    let mut index = 0;
+    // Boyuttan önceki isteğe bağlı anahtar kelimeler
+    while index < parts.len() {
+        match parts[index] {
+            [Token::Ident(keyword)] => match keyword.to_lowercase().as_str() {
+                "normal" => {}
+                "italic" | "oblique" if style.is_none() => style = Some(Value::Keyword(keyword.to_lowercase())),
+                "small-caps" if variant.is_none() => variant = Some(Value::Keyword(keyword.to_lowercase())),
+                "bold" | "bolder" | "lighter" if weight.is_none() => weight = Some(Value::Keyword(keyword.to_lowercase())),
+                _ => break,
+            },
+            [Token::Number(number)] if weight.is_none() && (1.0..=1000.0).contains(number) => weight = Some(Value::Number(*number)),
+            _ => break,
+        }
+        index += 1;
+    }
+
+    // Boyut ve isteğe bağlı `/satır-yüksekliği`; bölü işareti boşluksuz ya da boşluklu yazılabilir
+    let rest: Vec<Token> = parts[index..].join(&Token::Whitespace);
+    let mut slash_parts = split_top_level(&rest, &Token::Delim('/')).into_iter();
+    let size_and_more = slash_parts.next().unwrap_or(&[]);
+    let size_parts = components(size_and_more);
+    let Some((size_tokens, family_after_size)) = size_parts.split_first() else {
+        return Err("Missing font size".to_string());
+    };
+    let size = parse_value("font-size", size_tokens)?;
+    if !is_font_size(&size) {
+        return Err(format!("Invalid font size: {}", serialize_tokens(size_tokens)));
+    }
+
+    let (line_height, family_parts) = match slash_parts.next() {
+        Some(after_slash) => {
+            let after_slash = components(after_slash);
+            let Some((line_height, family)) = after_slash.split_first() else {
+                return Err("Missing line height after '/'".to_string());
+            };
+            if !family_after_size.is_empty() {
+                return Err("Unexpected value before '/'".to_string());
+            }
+            (Some(parse_value("line-height", line_height)?), family.to_vec())
+        }
+        None => (None, family_after_size.to_vec()),
+    };
+    if family_parts.is_empty() {
+        return Err("Missing font family".to_string());
+    }
+    let family = parse_value("font-family", &family_parts.join(&Token::Whitespace))?;
+
+    Ok(vec![
+        ("font-style".to_string(), style.unwrap_or_else(initial)),
+        ("font-variant".to_string(), variant.unwrap_or_else(initial)),
+        ("font-weight".to_string(), weight.unwrap_or_else(initial)),
+        ("font-size".to_string(), size),
+        ("line-height".to_string(), line_height.unwrap_or_else(initial)),
+        ("font-family".to_string(), family),
+    ])
+}
+
+// `background`: renk, görsel ve tekrar bileşenleri; kalan bileşenler konum sayılır
+fn expand_background(tokens: &[Token]) -> ParseResult<Vec<(String, Value)>> {
+    let (mut color, mut image, mut repeat) = (None, None, None);
+    let mut position = vec![];
+    for component in components(tokens) {
+        let value = parse_value("background", component)?;
+        match component {
+            [Token::Url(_)] | [Token::Function(_), ..] if image.is_none() && !is_color(&value) => image = Some(value),
+            [Token::Ident(keyword)] if keyword.eq_ignore_ascii_case("none") && image.is_none() => image = Some(value),
+            [Token::Ident(keyword)] if repeat.is_none()
+                && matches!(keyword.to_lowercase().as_str(), "repeat" | "repeat-x" | "repeat-y" | "no-repeat" | "space" | "round") => {
+                repeat = Some(value)
+            }
+            _ if color.is_none() && is_color(&value) => color = Some(value),
+            _ => position.push(value),
+        }
+    }
+    let initial = || Value::Keyword("initial".to_string());
+    let position = match position.len() {
+        0 => initial(),
+        1 => position.remove(0),
+        _ => Value::List(position),
+    };
+    Ok(vec![
+        ("background-color".to_string(), color.unwrap_or_else(initial)),
+        ("background-image".to_string(), image.unwrap_or_else(initial)),
+        ("background-repeat".to_string(), repeat.unwrap_or_else(initial)),
+        ("background-position".to_string(), position),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::tokenizer::Tokenizer;
+
+    fn expand_value(property: &str, value: &str) -> ParseResult<Vec<(String, Value)>> {
+        let tokens: Vec<Token> = Tokenizer::new(value).tokenize().into_iter().map(|(token, _)| token).collect();
+        expand(property, &tokens).unwrap()
+    }
+
+    fn expand_font_value(value: &str) -> ParseResult<Vec<(String, Value)>> {
+        expand_value("font", value)
+    }
+
+    fn font_size(value: &str) -> Value {
+        let expanded = expand_font_value(value).unwrap();
+        expanded.into_iter().find(|(name, _)| name == "font-size").unwrap().1
+    }
+
+    #[test]
+    fn font_accepts_length_percentage_and_size_keywords() {
+        assert!(matches!(font_size("bold 12px/1.5 Arial"), Value::Length(size, Unit::Px) if size == 12.0));
+        assert!(matches!(font_size("80% serif"), Value::Length(size, Unit::Percent) if size == 80.0));
+        assert!(matches!(font_size("italic x-large serif"), Value::Keyword(k) if k == "x-large"));
+        assert!(matches!(font_size("Smaller serif"), Value::Keyword(k) if k.eq_ignore_ascii_case("smaller")));
+        assert!(matches!(font_size("calc(1em + 2px) serif"), Value::Math(_)));
+    }
+
+    #[test]
+    fn font_rejects_other_values_as_size() {
+        assert!(expand_font_value("bold foo Arial").is_err());
+        assert!(expand_font_value("italic Arial").is_err());
+        assert!(expand_font_value("-2px Arial").is_err());
+        assert!(expand_font_value("#fff Arial").is_err());
+    }
+
+    #[test]
+    fn box_sides_accept_only_their_longhand_values() {
+        let expanded = expand_value("margin", "1px auto 10%").unwrap();
+        assert!(matches!(expanded[1], (ref name, Value::Keyword(ref k)) if name == "margin-right" && k == "auto"));
+        assert!(matches!(expanded[3], (ref name, Value::Keyword(ref k)) if name == "margin-left" && k == "auto"));
+        assert!(expand_value("margin", "red").is_err());
+        assert!(expand_value("margin", "1px ! important").is_err());
+        assert!(expand_value("padding", "auto").is_err());
+        assert!(expand_value("border-style", "solid wavy").is_err());
+        assert!(expand_value("border-color", "red currentcolor").is_ok());
+        assert!(expand_value("border-width", "1px 10%").is_err());
+    }
+
+    #[test]
+    fn background_rejects_unknown_parts() {
+        let expanded = expand_value("background", "red no-repeat center 10px").unwrap();
+        assert!(matches!(&expanded[3].1, Value::List(position) if position.len() == 2));
+        assert!(expand_value("background", "red bogus").is_err());
+        assert!(expand_value("background", "red blue").is_err());
+        assert!(expand_value("background", "1px solid").is_err());
+    }
+}
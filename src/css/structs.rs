@@ -2,12 +2,17 @@
 //!
 //! This module defines the core data structures for representing CSS stylesheets.
 
-use crate::css::enums::{Value, Selector};
+use crate::css::enums::{Value, Selector, Origin, Specificity, FontSource, FontStyle};
+use crate::css::media::MediaQueryList;
+use crate::html::structs::ElementData;
 
 /// Represents a complete CSS stylesheet
 #[derive(Debug, Clone)]
 pub struct StyleSheet {
     pub rules: Vec<Rule>,
+    pub origin: Origin,
+    pub layers: Vec<String>, // @layer adları, bildirim sırasıyla
+    pub font_faces: Vec<FontFace>,
 }
 
 /// Represents a CSS rule with selectors and declarations
@@ -15,6 +20,42 @@ pub struct StyleSheet {
 pub struct Rule {
     pub selectors: Vec<Selector>,
     pub declarations: Vec<Declaration>,
+    pub media: Vec<MediaQueryList>, // Kuralı saran @media sorguları; hepsi eşleşmelidir
+    pub layer: Option<String>, // Kuralın ait olduğu @layer katmanının tam adı
+}
+
+impl Rule {
+    // Elemana uyan seçiciler arasından en yüksek özgüllüğü döndürür
+    pub fn matching_specificity(&self, element: &ElementData) -> Option<Specificity> {
+        self.selectors.iter()
+            .filter(|selector| selector.matches(element))
+            .map(|selector| selector.specificity())
+            .max()
+    }
+}
+
+/// A web font declared with `@font-face`
+#[derive(Debug, Clone)]
+pub struct FontFace {
+    pub family: String,
+    pub sources: Vec<FontSource>, // Sırayla denenir; ilk yüklenebilen kullanılır
+    pub weight: (f32, f32), // Desteklenen font-weight aralığı
+    pub style: FontStyle,
+    pub stretch: (f32, f32), // Desteklenen font-stretch aralığı (yüzde)
+    pub unicode_range: Vec<(u32, u32)>, // Fontun kullanılacağı kod noktası aralıkları
+}
+
+impl Default for FontFace {
+    fn default() -> Self {
+        FontFace {
+            family: String::new(),
+            sources: vec![],
+            weight: (400.0, 400.0),
+            style: FontStyle::Normal,
+            stretch: (100.0, 100.0),
+            unicode_range: vec![(0, 0x10FFFF)],
+        }
+    }
 }
 
 /// Represents a CSS property declaration
@@ -22,4 +63,19 @@ pub struct Rule {
 pub struct Declaration {
     pub property: String,
     pub value: Value,
+    pub important: bool,
+}
+
+/// A recoverable problem found while parsing a stylesheet
+#[derive(Debug, Clone)]
+pub struct ParseWarning {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
 }
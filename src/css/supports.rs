@@ -0,0 +1,79 @@
+//! CSS feature queries module
+//!
+//! This module evaluates `@supports` conditions against the properties and values
+//! the engine actually implements.
+
+use super::enums::Token;
+use super::parser::{parse_declaration, parse_selectors, split_top_level, trim_whitespace};
+use crate::style::structs::is_valid_value;
+
+/// Evaluates the condition of an `@supports` rule or an `@import ... supports(...)` clause
+pub fn evaluate_supports(tokens: &[Token]) -> bool {
+    let parts: Vec<&[Token]> = split_top_level(trim_whitespace(tokens), &Token::Whitespace).into_iter()
+        .filter(|part| !part.is_empty())
+        .collect();
+    evaluate_condition(&parts).unwrap_or(false)
+}
+
+fn is_keyword(part: &[Token], keyword: &str) -> bool {
+    matches!(part, [Token::Ident(ident)] if ident.eq_ignore_ascii_case(keyword))
+}
+
+// not <parantezli> | <parantezli> [and <parantezli>]* | <parantezli> [or <parantezli>]*
+// Geçersiz koşullar `None` döndürür
+fn evaluate_condition(parts: &[&[Token]]) -> Option<bool> {
+    match parts {
+        [] => None,
+        [not, operand] if is_keyword(not, "not") => Some(!evaluate_in_parens(operand)?),
+        [first, rest @ ..] => {
+            let mut results = vec![evaluate_in_parens(first)?];
+            let mut operator: Option<String> = None;
+            for pair in rest.chunks(2) {
+                let [[Token::Ident(keyword)], operand] = pair else { return None };
+                let keyword = keyword.to_lowercase();
+                // `and` ve `or` parantez olmadan karıştırılamaz
+                if !matches!(keyword.as_str(), "and" | "or") || operator.as_ref().is_some_and(|op| *op != keyword) {
+                    return None;
+                }
+                operator = Some(keyword);
+                results.push(evaluate_in_parens(operand)?);
+            }
+            Some(match operator.as_deref() {
+                Some("or") => results.into_iter().any(|result| result),
+                _ => results.into_iter().all(|result| result),
+            })
+        }
+    }
+}
+
+// ( <koşul> ) | ( <bildirim> ) | selector(<seçici>); tanınmayan fonksiyonlar desteklenmiyor sayılır
+fn evaluate_in_parens(part: &[Token]) -> Option<bool> {
+    match part {
+        [Token::OpenParen, inner @ .., Token::CloseParen] => {
+            let inner = trim_whitespace(inner);
+            let parts: Vec<&[Token]> = split_top_level(inner, &Token::Whitespace).into_iter()
+                .filter(|part| !part.is_empty())
+                .collect();
+            if matches!(inner.first(), Some(Token::OpenParen)) || parts.first().is_some_and(|first| is_keyword(first, "not")) {
+                return Some(evaluate_condition(&parts).unwrap_or(false));
+            }
+            Some(supports_declaration(inner))
+        }
+        [Token::Function(name), args @ .., Token::CloseParen] if name.eq_ignore_ascii_case("selector") => {
+            let args: Vec<_> = trim_whitespace(args).iter().map(|token| (token.clone(), 0)).collect();
+            Some(parse_selectors(&args).is_ok())
+        }
+        [Token::Function(_), .., Token::CloseParen] => Some(false),
+        _ => None,
+    }
+}
+
+// Bildirim, özellik motor tarafından tanınıyor ve değer ayrıştırılabiliyorsa desteklenir
+fn supports_declaration(tokens: &[Token]) -> bool {
+    let tokens: Vec<_> = tokens.iter().map(|token| (token.clone(), 0)).collect();
+    let Ok(declarations) = parse_declaration(&tokens) else {
+        return false;
+    };
+    // Kısaltmaların uzun biçimleri de kendi değerleriyle doğrulanır
+    declarations.iter().all(|declaration| is_valid_value(&declaration.property, &declaration.value))
+}
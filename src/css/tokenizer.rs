@@ -0,0 +1,490 @@
+//! CSS tokenizer module
+//!
+//! This module splits CSS source text into tokens as described in CSS Syntax Level 3.
+
+use super::enums::Token;
+
+/// A token together with the character offset where it starts in the source
+pub type PositionedToken = (Token, usize);
+
+/// CSS tokenizer that converts CSS source text into a list of tokens
+pub struct Tokenizer {
+    pos: usize,
+    input: Vec<char>,
+}
+
+// Tanımlayıcı başlangıç karakteri mi (harf, `_` veya ASCII olmayan)
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+}
+
+// Tanımlayıcı içinde geçebilecek bir karakter mi
+fn is_name_char(c: char) -> bool {
+    is_name_start(c) || c.is_ascii_digit() || c == '-'
+}
+
+// CSS yalnızca boşluk, sekme ve satır sonunu boşluk sayar
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n')
+}
+
+// Yazdırılamayan karakterler `url()` içinde geçersizdir
+fn is_non_printable(c: char) -> bool {
+    matches!(c, '\u{0}'..='\u{8}' | '\u{b}' | '\u{e}'..='\u{1f}' | '\u{7f}')
+}
+
+/// Applies CSS input preprocessing: CRLF, CR and FF become line feeds and NULL becomes U+FFFD.
+/// Token positions are offsets into the preprocessed text.
+pub fn preprocess(input: &str) -> String {
+    input.replace("\r\n", "\n").replace(['\r', '\u{c}'], "\n").replace('\0', "\u{fffd}")
+}
+
+impl Tokenizer {
+    pub fn new(input: &str) -> Tokenizer {
+        Tokenizer { pos: 0, input: preprocess(input).chars().collect() }
+    }
+
+    // Belirtilen uzaklıktaki karaktere bakar, tüketmez
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.input.get(self.pos + offset).copied()
+    }
+
+    fn consume_char(&mut self) -> Option<char> {
+        let c = self.peek(0);
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn eof(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        s.chars().enumerate().all(|(i, c)| self.peek(i) == Some(c))
+    }
+
+    // Girdinin tamamını token listesine çevirir; yorumlar atlanır
+    pub fn tokenize(&mut self) -> Vec<PositionedToken> {
+        let mut tokens = vec![];
+        loop {
+            self.consume_comments();
+            let start = self.pos;
+            match self.consume_token() {
+                Some(token) => tokens.push((token, start)),
+                None => break,
+            }
+        }
+        tokens
+    }
+
+    fn consume_comments(&mut self) {
+        while self.starts_with("/*") {
+            self.pos += 2;
+            while !self.eof() && !self.starts_with("*/") {
+                self.pos += 1;
+            }
+            // Kapanmamış yorum dosya sonuna kadar sürer
+            self.pos = (self.pos + 2).min(self.input.len());
+        }
+    }
+
+    fn consume_token(&mut self) -> Option<Token> {
+        let c = self.consume_char()?;
+        let token = match c {
+            c if is_whitespace(c) => {
+                while self.peek(0).is_some_and(is_whitespace) {
+                    self.pos += 1;
+                }
+                Token::Whitespace
+            }
+            '"' | '\'' => self.consume_string(c),
+            '#' => {
+                if self.peek(0).is_some_and(is_name_char) || self.is_valid_escape(0) {
+                    let is_id = self.starts_identifier(0);
+                    Token::Hash(self.consume_name(), is_id)
+                } else {
+                    Token::Delim('#')
+                }
+            }
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '[' => Token::OpenSquare,
+            ']' => Token::CloseSquare,
+            '{' => Token::OpenCurly,
+            '}' => Token::CloseCurly,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            ';' => Token::Semicolon,
+            '+' | '.' => {
+                self.pos -= 1;
+                if self.starts_number(0) {
+                    self.consume_numeric()
+                } else {
+                    self.pos += 1;
+                    Token::Delim(c)
+                }
+            }
+            '-' => {
+                self.pos -= 1;
+                if self.starts_number(0) {
+                    self.consume_numeric()
+                } else if self.starts_with("-->") {
+                    self.pos += 3;
+                    Token::Cdc
+                } else if self.starts_identifier(0) {
+                    self.consume_ident_like()
+                } else {
+                    self.pos += 1;
+                    Token::Delim('-')
+                }
+            }
+            '<' => {
+                if self.starts_with("!--") {
+                    self.pos += 3;
+                    Token::Cdo
+                } else {
+                    Token::Delim('<')
+                }
+            }
+            '@' => {
+                if self.starts_identifier(0) {
+                    Token::AtKeyword(self.consume_name())
+                } else {
+                    Token::Delim('@')
+                }
+            }
+            '\\' => {
+                self.pos -= 1;
+                if self.is_valid_escape(0) {
+                    self.consume_ident_like()
+                } else {
+                    self.pos += 1;
+                    Token::Delim('\\')
+                }
+            }
+            c if c.is_ascii_digit() => {
+                self.pos -= 1;
+                self.consume_numeric()
+            }
+            c if is_name_start(c) => {
+                self.pos -= 1;
+                self.consume_ident_like()
+            }
+            c => Token::Delim(c),
+        };
+        Some(token)
+    }
+
+    // `\` ile başlayan ve satır sonu ile bitmeyen bir kaçış dizisi mi
+    fn is_valid_escape(&self, offset: usize) -> bool {
+        self.peek(offset) == Some('\\') && self.peek(offset + 1).is_some_and(|c| c != '\n')
+    }
+
+    // Sonraki karakterler bir tanımlayıcı başlatıyor mu
+    fn starts_identifier(&self, offset: usize) -> bool {
+        match self.peek(offset) {
+            Some('-') => {
+                self.peek(offset + 1).is_some_and(|c| is_name_start(c) || c == '-') || self.is_valid_escape(offset + 1)
+            }
+            Some('\\') => self.is_valid_escape(offset),
+            Some(c) => is_name_start(c),
+            None => false,
+        }
+    }
+
+    // Sonraki karakterler bir sayı başlatıyor mu
+    fn starts_number(&self, offset: usize) -> bool {
+        match self.peek(offset) {
+            Some('+') | Some('-') => match self.peek(offset + 1) {
+                Some('.') => self.peek(offset + 2).is_some_and(|c| c.is_ascii_digit()),
+                Some(c) => c.is_ascii_digit(),
+                None => false,
+            },
+            Some('.') => self.peek(offset + 1).is_some_and(|c| c.is_ascii_digit()),
+            Some(c) => c.is_ascii_digit(),
+            None => false,
+        }
+    }
+
+    fn consume_escape(&mut self) -> char {
+        // `\` karakteri daha önce tüketilmiş olmalı
+        match self.consume_char() {
+            Some(c) if c.is_ascii_hexdigit() => {
+                let mut hex = c.to_string();
+                while hex.len() < 6 && self.peek(0).is_some_and(|c| c.is_ascii_hexdigit()) {
+                    hex.push(self.consume_char().unwrap());
+                }
+                if self.peek(0).is_some_and(is_whitespace) {
+                    self.pos += 1;
+                }
+                u32::from_str_radix(&hex, 16).ok()
+                    .filter(|&code| code != 0)
+                    .and_then(char::from_u32)
+                    .unwrap_or('\u{fffd}')
+            }
+            Some(c) => c,
+            None => '\u{fffd}',
+        }
+    }
+
+    fn consume_name(&mut self) -> String {
+        let mut name = String::new();
+        loop {
+            match self.peek(0) {
+                Some(c) if is_name_char(c) => {
+                    name.push(c);
+                    self.pos += 1;
+                }
+                Some('\\') if self.is_valid_escape(0) => {
+                    self.pos += 1;
+                    name.push(self.consume_escape());
+                }
+                _ => break,
+            }
+        }
+        name
+    }
+
+    fn consume_number(&mut self) -> f32 {
+        let mut repr = String::new();
+        if let Some(sign @ ('+' | '-')) = self.peek(0) {
+            repr.push(sign);
+            self.pos += 1;
+        }
+        while self.peek(0).is_some_and(|c| c.is_ascii_digit()) {
+            repr.push(self.consume_char().unwrap());
+        }
+        if self.peek(0) == Some('.') && self.peek(1).is_some_and(|c| c.is_ascii_digit()) {
+            repr.push(self.consume_char().unwrap());
+            while self.peek(0).is_some_and(|c| c.is_ascii_digit()) {
+                repr.push(self.consume_char().unwrap());
+            }
+        }
+        // Üs kısmı (örn: 1e3, 2.5E-2)
+        if matches!(self.peek(0), Some('e' | 'E')) {
+            let digits_at = if matches!(self.peek(1), Some('+' | '-')) { 2 } else { 1 };
+            if self.peek(digits_at).is_some_and(|c| c.is_ascii_digit()) {
+                for _ in 0..digits_at {
+                    repr.push(self.consume_char().unwrap());
+                }
+                while self.peek(0).is_some_and(|c| c.is_ascii_digit()) {
+                    repr.push(self.consume_char().unwrap());
+                }
+            }
+        }
+        repr.parse::<f32>().unwrap_or(0.0)
+    }
+
+    fn consume_numeric(&mut self) -> Token {
+        let number = self.consume_number();
+        if self.starts_identifier(0) {
+            Token::Dimension(number, self.consume_name())
+        } else if self.peek(0) == Some('%') {
+            self.pos += 1;
+            Token::Percentage(number)
+        } else {
+            Token::Number(number)
+        }
+    }
+
+    fn consume_ident_like(&mut self) -> Token {
+        let name = self.consume_name();
+        if self.peek(0) != Some('(') {
+            return Token::Ident(name);
+        }
+        self.pos += 1;
+        if name.eq_ignore_ascii_case("url") {
+            // Tırnaklı url("...") normal bir fonksiyon gibi işlenir
+            let mut offset = 0;
+            while self.peek(offset).is_some_and(is_whitespace) {
+                offset += 1;
+            }
+            if !matches!(self.peek(offset), Some('"' | '\'')) {
+                return self.consume_url();
+            }
+        }
+        Token::Function(name)
+    }
+
+    fn consume_string(&mut self, quote: char) -> Token {
+        let mut value = String::new();
+        loop {
+            match self.consume_char() {
+                None => break, // Dosya sonu dizgeyi kapatır
+                Some(c) if c == quote => break,
+                Some('\n') => {
+                    // Kaçışsız satır sonu geçersiz bir dizge üretir
+                    self.pos -= 1;
+                    return Token::BadString;
+                }
+                Some('\\') => match self.peek(0) {
+                    None => {}
+                    Some('\n') => self.pos += 1,
+                    Some(_) => value.push(self.consume_escape()),
+                },
+                Some(c) => value.push(c),
+            }
+        }
+        Token::String(value)
+    }
+
+    fn consume_url(&mut self) -> Token {
+        let mut value = String::new();
+        while self.peek(0).is_some_and(is_whitespace) {
+            self.pos += 1;
+        }
+        loop {
+            match self.consume_char() {
+                None | Some(')') => return Token::Url(value),
+                Some(c) if is_whitespace(c) => {
+                    while self.peek(0).is_some_and(is_whitespace) {
+                        self.pos += 1;
+                    }
+                    return match self.consume_char() {
+                        None | Some(')') => Token::Url(value),
+                        Some(_) => self.consume_bad_url(),
+                    };
+                }
+                Some('"' | '\'' | '(') => return self.consume_bad_url(),
+                Some(c) if is_non_printable(c) => return self.consume_bad_url(),
+                Some('\\') => {
+                    if self.peek(0).is_some_and(|c| c != '\n') {
+                        value.push(self.consume_escape());
+                    } else {
+                        return self.consume_bad_url();
+                    }
+                }
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    // Geçersiz bir url'in kalanını kapanış parantezine kadar atlar
+    fn consume_bad_url(&mut self) -> Token {
+        loop {
+            match self.consume_char() {
+                None | Some(')') => return Token::BadUrl,
+                Some('\\') if self.peek(0).is_some() => {
+                    self.consume_escape();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        Tokenizer::new(input).tokenize().into_iter().map(|(token, _)| token).collect()
+    }
+
+    #[test]
+    fn escapes_in_identifiers_and_strings() {
+        // Onaltılık kaçıştan sonraki tek boşluk kaçışa aittir
+        assert_eq!(tokens(r"\41 BC"), vec![Token::Ident("ABC".into())]);
+        assert_eq!(tokens(r"a\:b"), vec![Token::Ident("a:b".into())]);
+        assert_eq!(tokens(r"\0 x"), vec![Token::Ident("\u{fffd}x".into())]);
+        assert_eq!(tokens(r"#\31 23"), vec![Token::Hash("123".into(), true)]);
+        assert_eq!(tokens(r#""a\"b""#), vec![Token::String("a\"b".into())]);
+        // Kaçışlı satır sonu dizgeye eklenmez
+        assert_eq!(tokens("'a\\\nb'"), vec![Token::String("ab".into())]);
+        // Satır sonundan önceki `\` geçerli bir kaçış değildir
+        assert_eq!(tokens("\\\n"), vec![Token::Delim('\\'), Token::Whitespace]);
+    }
+
+    #[test]
+    fn hash_tokens_record_whether_they_are_identifiers() {
+        assert_eq!(tokens("#main"), vec![Token::Hash("main".into(), true)]);
+        assert_eq!(tokens("#1a2b"), vec![Token::Hash("1a2b".into(), false)]);
+        assert_eq!(tokens("# "), vec![Token::Delim('#'), Token::Whitespace]);
+    }
+
+    #[test]
+    fn numbers_percentages_and_dimensions() {
+        assert_eq!(tokens("12"), vec![Token::Number(12.0)]);
+        assert_eq!(tokens("+3.5"), vec![Token::Number(3.5)]);
+        assert_eq!(tokens("-.5"), vec![Token::Number(-0.5)]);
+        assert_eq!(tokens("1e3"), vec![Token::Number(1000.0)]);
+        assert_eq!(tokens("2.5E-2"), vec![Token::Number(0.025)]);
+        assert_eq!(tokens("50%"), vec![Token::Percentage(50.0)]);
+        assert_eq!(tokens("10px"), vec![Token::Dimension(10.0, "px".into())]);
+        assert_eq!(tokens("-1.5em"), vec![Token::Dimension(-1.5, "em".into())]);
+        // Rakamsız üs bir birimdir
+        assert_eq!(tokens("1e"), vec![Token::Dimension(1.0, "e".into())]);
+        // Ondalık noktadan sonra rakam yoksa nokta sayıya ait değildir
+        assert_eq!(tokens("1.x"), vec![Token::Number(1.0), Token::Delim('.'), Token::Ident("x".into())]);
+        assert_eq!(tokens("+."), vec![Token::Delim('+'), Token::Delim('.')]);
+    }
+
+    #[test]
+    fn idents_functions_and_at_keywords() {
+        assert_eq!(tokens("-webkit-box"), vec![Token::Ident("-webkit-box".into())]);
+        assert_eq!(tokens("--main-color"), vec![Token::Ident("--main-color".into())]);
+        assert_eq!(tokens("rgb("), vec![Token::Function("rgb".into())]);
+        assert_eq!(tokens("@media"), vec![Token::AtKeyword("media".into())]);
+        assert_eq!(tokens("@ x"), vec![Token::Delim('@'), Token::Whitespace, Token::Ident("x".into())]);
+        assert_eq!(tokens("- x"), vec![Token::Delim('-'), Token::Whitespace, Token::Ident("x".into())]);
+    }
+
+    #[test]
+    fn url_tokens() {
+        assert_eq!(tokens("url(a.png)"), vec![Token::Url("a.png".into())]);
+        assert_eq!(tokens("URL(  a.png  )"), vec![Token::Url("a.png".into())]);
+        assert_eq!(tokens(r"url(a\)b)"), vec![Token::Url("a)b".into())]);
+        // Tırnaklı adres sıradan bir fonksiyondur
+        assert_eq!(tokens("url(\"a.png\")"), vec![Token::Function("url".into()), Token::String("a.png".into()), Token::CloseParen]);
+        // Kapanmamış url dosya sonunda biter
+        assert_eq!(tokens("url(a.png"), vec![Token::Url("a.png".into())]);
+    }
+
+    #[test]
+    fn bad_urls_skip_to_the_closing_parenthesis() {
+        assert_eq!(tokens("url(a b) x"), vec![Token::BadUrl, Token::Whitespace, Token::Ident("x".into())]);
+        assert_eq!(tokens("url(a\"b) x"), vec![Token::BadUrl, Token::Whitespace, Token::Ident("x".into())]);
+        assert_eq!(tokens("url(a(b) x"), vec![Token::BadUrl, Token::Whitespace, Token::Ident("x".into())]);
+        assert_eq!(tokens("url(a\u{1}b) x"), vec![Token::BadUrl, Token::Whitespace, Token::Ident("x".into())]);
+        // Kaçışlı `)` geçersiz url'i kapatmaz
+        assert_eq!(tokens(r"url(a b\)c) x"), vec![Token::BadUrl, Token::Whitespace, Token::Ident("x".into())]);
+    }
+
+    #[test]
+    fn comments_are_skipped() {
+        assert_eq!(tokens("a/* x */b"), vec![Token::Ident("a".into()), Token::Ident("b".into())]);
+        assert_eq!(tokens("/* a *//* b */c"), vec![Token::Ident("c".into())]);
+        assert_eq!(tokens("a /* unterminated"), vec![Token::Ident("a".into()), Token::Whitespace]);
+        // Dizge içindeki yorum işaretleri metindir
+        assert_eq!(tokens("'/* x */'"), vec![Token::String("/* x */".into())]);
+    }
+
+    #[test]
+    fn cdo_and_cdc() {
+        assert_eq!(tokens("<!-- a -->"), vec![Token::Cdo, Token::Whitespace, Token::Ident("a".into()), Token::Whitespace, Token::Cdc]);
+        assert_eq!(tokens("<!x"), vec![Token::Delim('<'), Token::Delim('!'), Token::Ident("x".into())]);
+    }
+
+    #[test]
+    fn recovers_from_bad_strings() {
+        // Kaçışsız satır sonu dizgeyi geçersiz kılar; satır sonu ve sonrası normal tokenlara ayrılır
+        assert_eq!(tokens("\"abc\ndef"), vec![Token::BadString, Token::Whitespace, Token::Ident("def".into())]);
+        // Dosya sonu dizgeyi kapatır
+        assert_eq!(tokens("'abc"), vec![Token::String("abc".into())]);
+    }
+
+    #[test]
+    fn preprocesses_newlines_and_null() {
+        assert_eq!(tokens("a\r\nb"), vec![Token::Ident("a".into()), Token::Whitespace, Token::Ident("b".into())]);
+        assert_eq!(tokens("a\u{c}b"), vec![Token::Ident("a".into()), Token::Whitespace, Token::Ident("b".into())]);
+        assert_eq!(tokens("a\0"), vec![Token::Ident("a\u{fffd}".into())]);
+    }
+
+    #[test]
+    fn records_token_offsets() {
+        let positions: Vec<usize> = Tokenizer::new("a { b: 1px }").tokenize().into_iter().map(|(_, position)| position).collect();
+        assert_eq!(positions, vec![0, 1, 2, 3, 4, 5, 6, 7, 10, 11]);
+    }
+}
@@ -0,0 +1,574 @@
+//! Font database module
+//!
+//! This module keeps the fonts available to layout and painting: the built-in default font,
+//! system fonts found in font directories or through fontconfig, and `@font-face` web fonts.
+//! A font is selected for each character with the CSS font matching algorithm.
+
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use rusttype::{point, Font, Scale};
+use crate::css::enums::{FontSource, FontStyle, Value};
+use crate::css::font_face::font_stretch_percentage;
+use crate::css::structs::{FontFace, StyleSheet};
+use crate::resource::structs::ResourceLoader;
+use crate::style::structs::StyledNode;
+
+/// A font face in the database together with the descriptors it is matched by.
+/// System fonts are only read from disk when they are first needed.
+pub struct FontEntry {
+    pub family: String,
+    pub weight: (f32, f32),
+    pub style: FontStyle,
+    pub stretch: (f32, f32),
+    pub unicode_range: Vec<(u32, u32)>,
+    path: Option<(PathBuf, u32)>, // Dosya yolu ve koleksiyon içindeki yüz indeksi
+    font: OnceCell<Option<Font<'static>>>,
+}
+
+impl FontEntry {
+    // Fontu gerektiğinde diskten yükler; yüklenemeyen font bir daha denenmez
+    fn font(&self) -> Option<&Font<'static>> {
+        self.font.get_or_init(|| {
+            let (path, index) = self.path.as_ref()?;
+            Font::try_from_vec_and_index(std::fs::read(path).ok()?, *index)
+        }).as_ref()
+    }
+
+    // Karakter fontun unicode-range'i içinde mi ve fontta bu karakter için bir glif var mı
+    fn covers(&self, character: char) -> bool {
+        let codepoint = character as u32;
+        self.unicode_range.iter().any(|(start, end)| (*start..=*end).contains(&codepoint))
+            && self.font().is_some_and(|font| font.glyph(character).id().0 != 0)
+    }
+}
+
+/// The fonts used to measure and paint text
+pub struct FontDatabase {
+    fonts: Vec<FontEntry>,
+    fallbacks: RefCell<HashMap<char, Vec<usize>>>, // Son çare yedek font aramasında her karakteri içeren fontların indeksleri
+}
+
+impl Default for FontDatabase {
+    // Hiçbir font eşleşmediğinde kullanılan gömülü varsayılan fontla başlar
+    fn default() -> Self {
+        let default_font = Font::try_from_bytes(include_bytes!("../../Arial.ttf") as &[u8]).expect("Failed to load the default font");
+        FontDatabase {
+            fonts: vec![FontEntry {
+                family: "Arial".to_string(),
+                weight: (400.0, 400.0),
+                style: FontStyle::Normal,
+                stretch: (100.0, 100.0),
+                unicode_range: vec![(0, 0x10FFFF)],
+                path: None,
+                font: OnceCell::from(Some(default_font)),
+            }],
+            fallbacks: RefCell::default(),
+        }
+    }
+}
+
+// Genel aile adlarının sırayla denenen karşılıkları
+const GENERIC_FAMILIES: &[(&str, &[&str])] = &[
+    ("serif", &["Times New Roman", "Liberation Serif", "DejaVu Serif", "Noto Serif"]),
+    ("sans-serif", &["Arial", "Helvetica", "Liberation Sans", "DejaVu Sans", "Noto Sans"]),
+    ("monospace", &["Courier New", "Liberation Mono", "DejaVu Sans Mono", "Noto Sans Mono"]),
+    ("cursive", &["Comic Sans MS", "URW Chancery L"]),
+    ("fantasy", &["Impact", "Papyrus"]),
+    ("system-ui", &["Segoe UI", "Cantarell", "Ubuntu", "DejaVu Sans", "Arial"]),
+];
+
+// Fontconfig bulunamadığında taranan sistem font dizinleri
+const SYSTEM_FONT_DIRECTORIES: &[&str] = &[
+    "/usr/share/fonts",
+    "/usr/local/share/fonts",
+    "~/.fonts",
+    "~/.local/share/fonts",
+    "/System/Library/Fonts",
+    "/Library/Fonts",
+    "~/Library/Fonts",
+    "C:\\Windows\\Fonts",
+];
+
+impl FontDatabase {
+    /// Adds every TrueType/OpenType font found in the directory and its subdirectories
+    pub fn load_fonts_dir(&mut self, directory: &Path) {
+        let Ok(entries) = std::fs::read_dir(directory) else { return };
+        let mut paths: Vec<PathBuf> = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect();
+        paths.sort();
+        for path in paths {
+            if path.is_dir() {
+                self.load_fonts_dir(&path);
+            } else {
+                self.load_font_file(&path);
+            }
+        }
+    }
+
+    /// Adds the fonts installed on the system: the fonts listed by fontconfig when it is
+    /// available, otherwise the fonts in the platform's usual font directories
+    pub fn load_system_fonts(&mut self) {
+        let fontconfig = std::process::Command::new("fc-list")
+            .args(["--format", "%{file}\n"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success());
+        if let Some(output) = fontconfig {
+            let mut paths: Vec<PathBuf> = String::from_utf8_lossy(&output.stdout).lines().map(PathBuf::from).collect();
+            paths.sort();
+            paths.dedup();
+            for path in paths {
+                self.load_font_file(&path);
+            }
+            return;
+        }
+        let home = std::env::var("HOME").unwrap_or_default();
+        for directory in SYSTEM_FONT_DIRECTORIES {
+            self.load_fonts_dir(Path::new(&directory.replacen('~', &home, 1)));
+        }
+    }
+
+    // Font dosyasındaki (koleksiyonlarda her) yüzün adını ve stilini okuyup kaydeder.
+    // Glifler kullanılana kadar yüklenmez.
+    fn load_font_file(&mut self, path: &Path) {
+        let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or_default().to_lowercase();
+        if !matches!(extension.as_str(), "ttf" | "otf" | "ttc" | "otc") {
+            return;
+        }
+        if self.fonts.iter().any(|entry| entry.path.as_ref().is_some_and(|(loaded, _)| loaded == path)) {
+            return;
+        }
+        let Ok(data) = std::fs::read(path) else { return };
+        let faces = ttf_parser::fonts_in_collection(&data).unwrap_or(1);
+        for index in 0..faces {
+            let Ok(face) = ttf_parser::Face::from_slice(&data, index) else { continue };
+            let Some(family) = family_name(&face) else { continue };
+            let style = if face.is_italic() {
+                FontStyle::Italic
+            } else if face.is_oblique() {
+                FontStyle::Oblique
+            } else {
+                FontStyle::Normal
+            };
+            let weight = face.weight().to_number() as f32;
+            let stretch = width_percentage(face.width());
+            self.fonts.push(FontEntry {
+                family,
+                weight: (weight, weight),
+                style,
+                stretch: (stretch, stretch),
+                unicode_range: vec![(0, 0x10FFFF)],
+                path: Some((path.to_path_buf(), index)),
+                font: OnceCell::new(),
+            });
+        }
+        // Yeni yüzler önbellekteki kapsama listelerini geçersiz kılar
+        self.fallbacks.get_mut().clear();
+    }
+
+    /// Loads the `@font-face` rules of the stylesheets through the loader.
+    /// Returns a warning for every font face none of whose sources could be loaded.
+    pub fn load_font_faces(&mut self, stylesheets: &[StyleSheet], loader: &dyn ResourceLoader) -> Vec<String> {
+        let mut warnings = vec![];
+        for face in stylesheets.iter().flat_map(|stylesheet| stylesheet.font_faces.iter()) {
+            match self.load_font_face(face, loader) {
+                Some(font) => self.fonts.push(font),
+                None => warnings.push(format!("Cannot load any source of font family '{}'", face.family)),
+            }
+        }
+        self.fallbacks.get_mut().clear();
+        warnings
+    }
+
+    // Kaynakları sırayla dener; ilk yüklenebilen font, kuraldaki tanımlayıcılarla kaydedilir
+    fn load_font_face(&self, face: &FontFace, loader: &dyn ResourceLoader) -> Option<FontEntry> {
+        let font = face.sources.iter().find_map(|source| match source {
+            FontSource::Url(url) => loader.load(url).and_then(Font::try_from_vec),
+            // local() sistemde yüklü fontlar arasında aile adına ve stile göre aranır
+            FontSource::Local(name) => {
+                let query = FontQuery {
+                    families: vec![name.clone()],
+                    weight: face.weight.0,
+                    style: face.style,
+                    stretch: face.stretch.0,
+                    size: 16.0,
+                };
+                let candidates = self.fonts.iter().filter(|entry| entry.family.eq_ignore_ascii_case(name)).collect();
+                match_face(candidates, &query).and_then(FontEntry::font).cloned()
+            }
+        })?;
+        Some(FontEntry {
+            family: face.family.clone(),
+            weight: face.weight,
+            style: face.style,
+            stretch: face.stretch,
+            unicode_range: face.unicode_range.clone(),
+            path: None,
+            font: OnceCell::from(Some(font)),
+        })
+    }
+
+    /// Selects the font used to draw a character: the best matching face of the first family
+    /// in the list that covers the character, then any font covering it, then the default font
+    pub fn select(&self, query: &FontQuery, character: char) -> &Font<'static> {
+        let default_font = || self.fonts[0].font().expect("The default font is always loaded");
+        let families = query.families.iter().flat_map(|family| {
+            match GENERIC_FAMILIES.iter().find(|(generic, _)| family.eq_ignore_ascii_case(generic)) {
+                Some((_, families)) => families.iter().map(|family| family.to_string()).collect(),
+                None => vec![family.clone()],
+            }
+        });
+        for family in families {
+            let candidates = self.fonts.iter()
+                .filter(|entry| entry.family.eq_ignore_ascii_case(&family) && entry.covers(character))
+                .collect();
+            if let Some(entry) = match_face(candidates, query) {
+                return entry.font().unwrap_or_else(default_font);
+            }
+        }
+        // Listedeki hiçbir aile karakteri içermiyorsa karakteri içeren herhangi bir font kullanılır.
+        // Tüm fontları taramak pahalı olduğundan karakteri içeren fontlar karakter başına bir kez bulunur.
+        let mut fallbacks = self.fallbacks.borrow_mut();
+        let covering = fallbacks.entry(character).or_insert_with(|| {
+            self.fonts.iter().enumerate()
+                .filter(|(_, entry)| entry.covers(character))
+                .map(|(index, _)| index)
+                .collect()
+        });
+        let candidates = covering.iter().map(|index| &self.fonts[*index]).collect();
+        match_face(candidates, query).and_then(FontEntry::font).unwrap_or_else(default_font)
+    }
+
+    /// Splits the text into runs of consecutive characters drawn with the same font
+    pub fn runs<'a>(&'a self, text: &str, query: &FontQuery) -> Vec<(&'a Font<'static>, String)> {
+        let mut runs: Vec<(&Font<'static>, String)> = vec![];
+        for character in text.chars() {
+            let font = self.select(query, character);
+            match runs.last_mut() {
+                Some((run_font, run)) if std::ptr::eq(*run_font, font) => run.push(character),
+                _ => runs.push((font, character.to_string())),
+            }
+        }
+        runs
+    }
+
+    /// Measures the text as it is painted: advance widths and kerning of every font run,
+    /// and the largest ascent, descent and line gap of the fonts used
+    pub fn measure(&self, text: &str, query: &FontQuery) -> TextMetrics {
+        // Boş metinde de satır yüksekliği, boşluk karakterini içeren ilk fonttan (birincil font) gelir
+        let primary = self.select(query, ' ');
+        let mut metrics = TextMetrics::default();
+        let runs = self.runs(text, query);
+        for font in std::iter::once(primary).chain(runs.iter().map(|(font, _)| *font)) {
+            let v_metrics = font.v_metrics(em_scale(font, query.size));
+            metrics.ascent = metrics.ascent.max(v_metrics.ascent);
+            metrics.descent = metrics.descent.max(-v_metrics.descent);
+            metrics.line_gap = metrics.line_gap.max(v_metrics.line_gap);
+        }
+        for (font, run) in &runs {
+            metrics.width += run_width(font, run, query.size);
+        }
+        metrics
+    }
+}
+
+/// Returns the rusttype scale at which the font's em square is `size` pixels, as CSS `font-size` requires.
+/// (`Scale::uniform` alone would make the font's ascent-to-descent height `size` pixels.)
+pub fn em_scale(font: &Font<'static>, size: f32) -> Scale {
+    let v_metrics = font.v_metrics_unscaled();
+    Scale::uniform(size * (v_metrics.ascent - v_metrics.descent) / font.units_per_em() as f32)
+}
+
+/// Measures the width of a run drawn with one font: the last glyph's position and advance,
+/// including the kerning between glyphs
+pub fn run_width(font: &Font<'static>, run: &str, size: f32) -> f32 {
+    font.layout(run, em_scale(font, size), point(0.0, 0.0))
+        .last()
+        .map_or(0.0, |glyph| glyph.position().x + glyph.unpositioned().h_metrics().advance_width)
+}
+
+/// Horizontal and vertical measurements of a piece of text
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextMetrics {
+    pub width: f32,
+    pub ascent: f32, // Taban çizgisinin üstü
+    pub descent: f32, // Taban çizgisinin altı (pozitif)
+    pub line_gap: f32,
+}
+
+impl TextMetrics {
+    // Fontun önerdiği satır yüksekliği (`line-height: normal`)
+    pub fn line_height(&self) -> f32 {
+        self.ascent + self.descent + self.line_gap
+    }
+}
+
+// Fontun aile adı; tipografik aile adı (örn: "Open Sans") varsa o tercih edilir
+fn family_name(face: &ttf_parser::Face) -> Option<String> {
+    let name = |id: u16| face.names().into_iter()
+        .filter(|name| name.name_id == id)
+        // İngilizce adlar önce gelir
+        .max_by_key(|name| name.language_id == 0x0409)
+        .and_then(|name| name.to_string());
+    name(ttf_parser::name_id::TYPOGRAPHIC_FAMILY).or_else(|| name(ttf_parser::name_id::FAMILY))
+}
+
+// OS/2 genişlik sınıfının font-stretch yüzdesi karşılığı
+fn width_percentage(width: ttf_parser::Width) -> f32 {
+    match width.to_number() {
+        1 => 50.0,
+        2 => 62.5,
+        3 => 75.0,
+        4 => 87.5,
+        6 => 112.5,
+        7 => 125.0,
+        8 => 150.0,
+        9 => 200.0,
+        _ => 100.0,
+    }
+}
+
+// CSS font eşleştirme algoritması: adaylar sırasıyla font-stretch, font-style ve
+// font-weight'e göre elenir
+fn match_face<'a>(mut candidates: Vec<&'a FontEntry>, query: &FontQuery) -> Option<&'a FontEntry> {
+    let best_stretch = candidates.iter()
+        .map(|entry| stretch_distance(entry.stretch, query.stretch))
+        .min_by(f32::total_cmp)?;
+    candidates.retain(|entry| stretch_distance(entry.stretch, query.stretch) == best_stretch);
+
+    let style_order = match query.style {
+        FontStyle::Italic => [FontStyle::Italic, FontStyle::Oblique, FontStyle::Normal],
+        FontStyle::Oblique => [FontStyle::Oblique, FontStyle::Italic, FontStyle::Normal],
+        FontStyle::Normal => [FontStyle::Normal, FontStyle::Oblique, FontStyle::Italic],
+    };
+    let style = style_order.into_iter().find(|style| candidates.iter().any(|entry| entry.style == *style))?;
+    candidates.retain(|entry| entry.style == style);
+
+    candidates.into_iter().min_by(|a, b| {
+        weight_distance(a.weight, query.weight).total_cmp(&weight_distance(b.weight, query.weight))
+    })
+}
+
+// font-stretch uzaklığı: istenen değer %100 ya da daha darsa önce daha dar yüzler,
+// daha genişse önce daha geniş yüzler tercih edilir
+fn stretch_distance((min, max): (f32, f32), desired: f32) -> f32 {
+    let nearest = desired.clamp(min, max);
+    let prefer_narrower = desired <= 100.0;
+    match nearest.total_cmp(&desired) {
+        std::cmp::Ordering::Equal => 0.0,
+        std::cmp::Ordering::Less if prefer_narrower => desired - nearest,
+        std::cmp::Ordering::Greater if !prefer_narrower => nearest - desired,
+        _ => 1000.0 + (nearest - desired).abs(),
+    }
+}
+
+// font-weight uzaklığı: 400–500 arası istenirse önce 500'e kadar daha kalın, sonra daha ince,
+// sonra 500'den kalın yüzler; 400'den ince istenirse önce daha ince; 500'den kalın istenirse
+// önce daha kalın yüzler tercih edilir
+fn weight_distance((min, max): (f32, f32), desired: f32) -> f32 {
+    let nearest = desired.clamp(min, max);
+    let distance = (nearest - desired).abs();
+    if nearest == desired {
+        0.0
+    } else if (400.0..=500.0).contains(&desired) {
+        match nearest > desired {
+            true if nearest <= 500.0 => distance,
+            false => 1000.0 + distance,
+            true => 2000.0 + distance,
+        }
+    } else if (desired < 400.0) == (nearest < desired) {
+        distance
+    } else {
+        1000.0 + distance
+    }
+}
+
+/// The font properties of an element used to select fonts
+#[derive(Debug, Clone)]
+pub struct FontQuery {
+    pub families: Vec<String>,
+    pub weight: f32,
+    pub style: FontStyle,
+    pub stretch: f32, // Yüzde olarak genişlik
+    pub size: f32,
+}
+
+impl FontQuery {
+    // Elemanın hesaplanmış font özelliklerinden sorgu oluşturur
+    pub fn from_styled_node(styled_node: &StyledNode) -> FontQuery {
+        let families = match styled_node.get_property("font-family") {
+            Some(Value::Keyword(families)) => parse_family_list(families),
+            _ => vec![],
+        };
+        let weight = match styled_node.get_property("font-weight") {
+            Some(Value::Number(weight)) => *weight,
+            _ => 400.0,
+        };
+        let style = match styled_node.get_property("font-style") {
+            Some(Value::Keyword(keyword)) if keyword == "italic" => FontStyle::Italic,
+            Some(Value::Keyword(keyword)) if keyword == "oblique" => FontStyle::Oblique,
+            _ => FontStyle::Normal,
+        };
+        let stretch = match styled_node.get_property("font-stretch") {
+            Some(Value::Keyword(keyword)) => font_stretch_percentage(keyword).unwrap_or(100.0),
+            Some(Value::Length(percentage, _)) => *percentage,
+            _ => 100.0,
+        };
+        let size = match styled_node.get_property("font-size") {
+            Some(Value::Length(size, _)) => *size,
+            _ => 16.0,
+        };
+        FontQuery { families, weight, style, stretch, size }
+    }
+}
+
+// `"Open Sans", Arial, sans-serif` biçimindeki listeyi tırnakları atarak ailelere ayırır
+fn parse_family_list(families: &str) -> Vec<String> {
+    families.split(',')
+        .map(|family| family.trim().trim_matches(|c| c == '"' || c == '\'').to_string())
+        .filter(|family| !family.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::media::MediaEnvironment;
+    use crate::css::parser::Parser as CssParser;
+    use crate::html::parser::Parser as HtmlParser;
+    use crate::layout::structs::Dimensions;
+    use crate::resource::structs::FileLoader;
+    use crate::style::structs::style_tree;
+
+    // Verilen adlardaki fontları gömülü varsayılan fontun verisiyle yükler
+    struct FontLoader(&'static [&'static str]);
+
+    impl ResourceLoader for FontLoader {
+        fn load(&self, location: &str) -> Option<Vec<u8>> {
+            self.0.contains(&location).then(|| include_bytes!("../../Arial.ttf").to_vec())
+        }
+    }
+
+    // Stil sayfasındaki @font-face kurallarıyla bir veritabanı kurar; her yüz ayrı bir font nesnesidir
+    fn database(css: &str) -> (FontDatabase, Vec<String>) {
+        let mut fonts = FontDatabase::default();
+        let stylesheet = CssParser::new(css.to_string()).parse();
+        let warnings = fonts.load_font_faces(&[stylesheet], &FontLoader(&["a.ttf", "b.ttf"]));
+        (fonts, warnings)
+    }
+
+    // Seçilen fontun veritabanındaki sırası; 0 gömülü varsayılan fonttur
+    fn selected(fonts: &FontDatabase, query: &FontQuery, character: char) -> usize {
+        let font = fonts.select(query, character);
+        fonts.fonts.iter().position(|entry| entry.font().is_some_and(|candidate| std::ptr::eq(candidate, font))).unwrap()
+    }
+
+    fn query(families: &[&str]) -> FontQuery {
+        FontQuery {
+            families: families.iter().map(|family| family.to_string()).collect(),
+            weight: 400.0,
+            style: FontStyle::Normal,
+            stretch: 100.0,
+            size: 16.0,
+        }
+    }
+
+    // Belgenin ilk çocuk elemanının hesaplanmış değerlerinden sorgu oluşturur
+    fn styled_query(html: &str, css: &str) -> FontQuery {
+        let dom = HtmlParser::new(html.to_string()).parse();
+        let stylesheets = vec![CssParser::new(css.to_string()).parse()];
+        let root = style_tree(&dom, &stylesheets, &Dimensions::default(), &MediaEnvironment::default());
+        FontQuery::from_styled_node(&root.children[0])
+    }
+
+    #[test]
+    fn font_style_keywords_are_case_insensitive() {
+        assert_eq!(styled_query("<div><p></p></div>", "p { font-style: ITALIC }").style, FontStyle::Italic);
+        assert_eq!(styled_query("<div><p></p></div>", "p { font-style: Oblique }").style, FontStyle::Oblique);
+        assert_eq!(styled_query("<div><p></p></div>", "p { font-stretch: Condensed }").stretch, 75.0);
+    }
+
+    #[test]
+    fn fallback_coverage_is_cached_per_character() {
+        let mut fonts = FontDatabase::default();
+        let default_font: *const Font = fonts.select(&query(&["Arial"]), 'a');
+        // Listedeki aile karakteri içeriyorsa yedek font aranmaz
+        assert!(fonts.fallbacks.borrow().is_empty());
+
+        assert!(std::ptr::eq(fonts.select(&query(&["Missing"]), 'a'), default_font));
+        assert!(std::ptr::eq(fonts.select(&query(&[]), 'a'), default_font));
+        assert!(std::ptr::eq(fonts.select(&query(&[]), '\u{E000}'), default_font));
+        assert_eq!(fonts.fallbacks.borrow().get(&'a'), Some(&vec![0]));
+        // Hiçbir fontun içermediği karakter boş listeyle önbelleğe alınır ve varsayılan fonta düşer
+        assert_eq!(fonts.fallbacks.borrow().get(&'\u{E000}'), Some(&vec![]));
+        assert_eq!(fonts.fallbacks.borrow().len(), 2);
+
+        fonts.load_font_faces(&[], &FileLoader);
+        assert!(fonts.fallbacks.borrow().is_empty());
+    }
+
+    #[test]
+    fn font_faces_load_their_first_available_source() {
+        let (fonts, warnings) = database("@font-face { font-family: Web; src: url(missing.ttf), url(b.ttf) } \
+                                          @font-face { font-family: Local; src: local(Missing), local(Arial) } \
+                                          @font-face { font-family: Gone; src: url(missing.ttf), local(Missing) }");
+        assert_eq!(warnings, vec!["Cannot load any source of font family 'Gone'"]);
+        assert_eq!(fonts.fonts.iter().map(|entry| entry.family.as_str()).collect::<Vec<_>>(), vec!["Arial", "Web", "Local"]);
+        assert_eq!(selected(&fonts, &query(&["web"]), 'a'), 1);
+        assert_eq!(selected(&fonts, &query(&["Local"]), 'a'), 2);
+    }
+
+    #[test]
+    fn family_list_is_tried_in_order_with_generic_families() {
+        let (fonts, _) = database("@font-face { font-family: Web; src: url(a.ttf) } \
+                                   @font-face { font-family: 'Times New Roman'; src: url(b.ttf) }");
+        assert_eq!(selected(&fonts, &query(&["Missing", "Web", "serif"]), 'a'), 1);
+        assert_eq!(selected(&fonts, &query(&["Missing", "serif", "Web"]), 'a'), 2);
+        assert_eq!(selected(&fonts, &query(&["Missing", "sans-serif"]), 'a'), 0);
+        assert_eq!(FontQuery::from_styled_node(&style_tree(
+            &HtmlParser::new("<div></div>".to_string()).parse(),
+            &[CssParser::new("div { font-family: \"Open Sans\", 'Web', serif }".to_string()).parse()],
+            &Dimensions::default(),
+            &MediaEnvironment::default(),
+        )).families, vec!["Open Sans", "Web", "serif"]);
+    }
+
+    #[test]
+    fn faces_are_matched_by_stretch_then_style_then_weight() {
+        let (fonts, _) = database("@font-face { font-family: F; src: url(a.ttf) } \
+                                   @font-face { font-family: F; src: url(a.ttf); font-style: italic } \
+                                   @font-face { font-family: F; src: url(a.ttf); font-weight: 700 } \
+                                   @font-face { font-family: F; src: url(a.ttf); font-stretch: 75% } \
+                                   @font-face { font-family: F; src: url(a.ttf); font-weight: 200 300 }");
+        let matched = |weight: f32, style: FontStyle, stretch: f32| {
+            selected(&fonts, &FontQuery { weight, style, stretch, ..query(&["F"]) }, 'a')
+        };
+        assert_eq!(matched(400.0, FontStyle::Normal, 100.0), 1);
+        // Genişlik stilden, stil kalınlıktan önce gelir
+        assert_eq!(matched(700.0, FontStyle::Normal, 75.0), 4);
+        assert_eq!(matched(700.0, FontStyle::Italic, 100.0), 2);
+        // Eğik yüz yoksa italik kullanılır; dar yüz yoksa genişlik için önce daha dar yüzlere bakılır
+        assert_eq!(matched(400.0, FontStyle::Oblique, 100.0), 2);
+        assert_eq!(matched(400.0, FontStyle::Normal, 80.0), 4);
+        assert_eq!(matched(400.0, FontStyle::Normal, 150.0), 1);
+        // 400–500 arası önce 500'e kadar daha kalın, sonra daha ince yüzlere bakar; 500'den kalın istekler daha kalın yüzleri seçer
+        assert_eq!(matched(500.0, FontStyle::Normal, 100.0), 1);
+        assert_eq!(matched(450.0, FontStyle::Normal, 100.0), 1);
+        assert_eq!(matched(600.0, FontStyle::Normal, 100.0), 3);
+        assert_eq!(matched(350.0, FontStyle::Normal, 100.0), 5);
+        assert_eq!(matched(250.0, FontStyle::Normal, 100.0), 5);
+        assert_eq!(matched(100.0, FontStyle::Normal, 100.0), 5);
+    }
+
+    #[test]
+    fn unicode_range_limits_the_characters_a_face_is_used_for() {
+        let (fonts, _) = database("@font-face { font-family: Latin; src: url(a.ttf); unicode-range: U+0-7F } \
+                                   @font-face { font-family: Latin; src: url(b.ttf); unicode-range: U+100-17F } \
+                                   @font-face { font-family: Other; src: url(a.ttf); unicode-range: U+80-FF }");
+        assert_eq!(selected(&fonts, &query(&["Latin", "Other"]), 'a'), 1);
+        assert_eq!(selected(&fonts, &query(&["Latin", "Other"]), 'ş'), 2);
+        // Ailenin hiçbir yüzü kapsamıyorsa sıradaki aileye geçilir
+        assert_eq!(selected(&fonts, &query(&["Latin", "Other"]), 'é'), 3);
+        assert_eq!(selected(&fonts, &query(&["Latin"]), 'é'), 0);
+    }
+}
@@ -105,6 +105,37 @@ pub enum TagName {
     Kbd,
     Q,
     Var,
+    B,
+    Strong,
+    I,
+    Em,
+    U,
+    S,
+    Small,
+    Sub,
+    Sup,
+    Code,
+    Pre,
+    Br,
+    Hr,
+    Blockquote,
+    Script,
+    Style,
+    Meta,
+    Link,
+    Header,
+    Footer,
+    Nav,
+    Section,
+    Main,
+    Figure,
+    Figcaption,
+    Dl,
+    Dt,
+    Dd,
+    Address,
+    Abbr,
+    Cite,
 }
 
 impl std::fmt::Display for TagName {
@@ -230,6 +230,37 @@ impl Parser {
             "kbd" => TagName::Kbd,
             "q" => TagName::Q,
             "var" => TagName::Var,
+            "b" => TagName::B,
+            "strong" => TagName::Strong,
+            "i" => TagName::I,
+            "em" => TagName::Em,
+            "u" => TagName::U,
+            "s" => TagName::S,
+            "small" => TagName::Small,
+            "sub" => TagName::Sub,
+            "sup" => TagName::Sup,
+            "code" => TagName::Code,
+            "pre" => TagName::Pre,
+            "br" => TagName::Br,
+            "hr" => TagName::Hr,
+            "blockquote" => TagName::Blockquote,
+            "script" => TagName::Script,
+            "style" => TagName::Style,
+            "meta" => TagName::Meta,
+            "link" => TagName::Link,
+            "header" => TagName::Header,
+            "footer" => TagName::Footer,
+            "nav" => TagName::Nav,
+            "section" => TagName::Section,
+            "main" => TagName::Main,
+            "figure" => TagName::Figure,
+            "figcaption" => TagName::Figcaption,
+            "dl" => TagName::Dl,
+            "dt" => TagName::Dt,
+            "dd" => TagName::Dd,
+            "address" => TagName::Address,
+            "abbr" => TagName::Abbr,
+            "cite" => TagName::Cite,
             _ => TagName::Html,
         }
     }
@@ -3,11 +3,13 @@
 //! This module defines the types of layout boxes used in the rendering engine.
 
 /// Represents the type of a layout box
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum LayoutBoxType {
     BlockNode,
-    #[allow(dead_code)]
     InlineNode,
-    AnonymousBlock,
-    LineBox,
+    InlineBlockNode, // Satır içinde bölünmeden yer alan blok kapsayıcı (atomik satır içi kutu)
+    AnonymousBlock, // Blok kapsayıcıdaki satır içi içerik dizisini saran, kendi stili olmayan blok
+    TextNode,
+    LineBox(f32), // Satırın taban çizgisinin y konumu
+    TextFragment(String), // Bir metin düğümünün tek satıra düşen parçası
 }
@@ -0,0 +1,1090 @@
+//! Inline formatting module
+//!
+//! This module lays out the inline content of a block container: text is split at
+//! Unicode line-break opportunities (UAX #14) and placed on as many line boxes as needed,
+//! splitting inline boxes that span several lines. Inline-blocks sit on lines as unbreakable units.
+
+use std::collections::VecDeque;
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+use super::enums::LayoutBoxType;
+use super::structs::{resolve_length, Continuation, LayoutBox, Rect};
+use crate::css::enums::Value;
+use crate::font::structs::{FontDatabase, FontQuery};
+use crate::html::enums::NodeType;
+use crate::style::structs::StyledNode;
+
+const SOFT_HYPHEN: char = '\u{AD}';
+
+/// A piece of inline content; text is split so that line breaks can only happen after a text item or an atomic box
+#[derive(Debug, Clone)]
+enum InlineItem<'a> {
+    Start(&'a StyledNode<'a>, f32, Continuation), // Satır içi elemanın başlangıcı ve sol kenarlarının toplamı
+    End(f32), // Satır içi elemanın sonu ve sağ kenarlarının toplamı
+    Text(TextItem<'a>),
+    Atomic(Box<LayoutBox<'a>>, Option<BreakOpportunity>), // Yerleştirilmiş satır içi blok ve ardındaki kırma fırsatı
+}
+
+#[derive(Debug, Clone)]
+struct TextItem<'a> {
+    node: &'a StyledNode<'a>,
+    text: String,
+    width: f32,
+    end_width: f32, // Satır bu parçadan sonra biterse genişliği (asılı boşluklar hariç, yumuşak tire görünür)
+    break_after: Option<BreakOpportunity>,
+    splittable: bool, // Satıra sığmayan kelime herhangi bir karakterden bölünebilir mi (overflow-wrap)
+}
+
+// Metin düğümünün `white-space` davranışı
+#[derive(Debug, Clone, Copy)]
+struct WhiteSpace {
+    collapse_spaces: bool,
+    preserve_newlines: bool,
+    wrap: bool,
+}
+
+impl WhiteSpace {
+    fn of(node: &StyledNode) -> WhiteSpace {
+        let (collapse_spaces, preserve_newlines, wrap) = match keyword(node, "white-space") {
+            "nowrap" => (true, false, false),
+            "pre" => (false, true, false),
+            "pre-wrap" | "break-spaces" => (false, true, true),
+            "pre-line" => (true, true, true),
+            _ => (true, false, true),
+        };
+        WhiteSpace { collapse_spaces, preserve_newlines, wrap }
+    }
+}
+
+// Kalıtılan anahtar kelime özelliğinin değeri; yoksa boş metin
+fn keyword<'a>(node: &'a StyledNode, property: &str) -> &'a str {
+    match node.get_property(property) {
+        Some(Value::Keyword(keyword)) => keyword,
+        _ => "",
+    }
+}
+
+/// Lays out inline boxes into line boxes starting at `y` inside the container's content box.
+/// `text-align` and `text-indent` come from the block container; the indent only applies when
+/// these lines start the container (`first_line`). Returns the line boxes; their heights add up
+/// to the height of the inline content.
+pub fn layout_lines<'a>(inline_boxes: Vec<LayoutBox<'a>>, container_node: Option<&StyledNode>, container: &Rect, y: f32, first_line: bool, fonts: &FontDatabase) -> Vec<LayoutBox<'a>> {
+    let mut items = vec![];
+    let mut after_space = true; // Satır başındaki ve ardışık daraltılabilir boşluklar atılır
+    for inline_box in inline_boxes {
+        collect_items(inline_box, container.width, container.width, fonts, &mut items, &mut after_space);
+    }
+    // Satır içi blokların içeriği yalnızca bu son geçişte yerleştirilir; ölçümler boyutlarıyla yetinir
+    for item in &mut items {
+        if let InlineItem::Atomic(atomic, _) = item {
+            atomic.layout_inline_block_contents(fonts);
+        }
+    }
+    let indent = match container_node.and_then(|node| node.get_property("text-indent")) {
+        Some(value) if first_line => resolve_length(value, container.width).unwrap_or(0.0),
+        _ => 0.0,
+    };
+    let alignment = container_node.map_or(TextAlign::Left, TextAlign::of);
+    let lines = break_lines(items, container.width, indent, fonts);
+
+    let mut line_boxes = vec![];
+    let mut open = vec![]; // Önceki satırdan devam eden satır içi elemanlar
+    let mut line_y = y;
+    let line_count = lines.len();
+    for (index, line) in lines.into_iter().enumerate() {
+        // Son satır ve zorunlu kırılmayla biten satırlar iki yana yaslanmaz
+        let forced_break = line.iter().rev()
+            .find_map(|item| match item {
+                InlineItem::Text(text) => Some(text.break_after == Some(BreakOpportunity::Mandatory)),
+                InlineItem::Atomic(..) => Some(false),
+                _ => None,
+            })
+            .unwrap_or(false);
+        // Metin, satır içi blok ya da kenarlı satır içi kutu içermeyen satırların yüksekliği yoktur
+        let empty = line.iter().all(|item| matches!(item, InlineItem::Start(_, edge, _) | InlineItem::End(edge) if *edge == 0.0));
+        let line_indent = if index == 0 { indent } else { 0.0 };
+        let mut line_box = build_line_box(line, &mut open, container_node, container, line_y, line_indent, fonts);
+        if empty {
+            line_box.dimensions.content.height = 0.0;
+        }
+        let alignment = match alignment {
+            TextAlign::Justify if index + 1 == line_count || forced_break => TextAlign::Left,
+            alignment => alignment,
+        };
+        align_line(&mut line_box, alignment, fonts);
+        line_y += line_box.dimensions.content.height;
+        line_boxes.push(line_box);
+    }
+    line_boxes
+}
+
+/// Returns the min-content and max-content widths of inline content: the widest piece that can't be broken
+/// and the widest line when lines only break where they must
+pub fn inline_content_widths(inline_boxes: &[&LayoutBox], fonts: &FontDatabase) -> (f32, f32) {
+    // Satır içi blokların genişlikleri kutular kopyalanmadan önce bir kez ölçülür; kopyalar önbelleği paylaşır
+    inline_boxes.iter().for_each(|inline_box| measure_inline_blocks(inline_box, fonts));
+    let widest_line = |available_width: f32| {
+        let mut items = vec![];
+        let mut after_space = true;
+        for inline_box in inline_boxes {
+            collect_items((*inline_box).clone(), 0.0, available_width, fonts, &mut items, &mut after_space);
+        }
+        break_lines(items, available_width, 0.0, fonts).iter()
+            .map(|line| chunk_end_width(line))
+            .fold(0.0, f32::max)
+    };
+    (widest_line(0.0), widest_line(f32::INFINITY))
+}
+
+// Ağaçtaki satır içi blokların içerik genişliklerini önbelleğe alır; iç içe bloklar kendi ölçümlerinde ölçülür
+fn measure_inline_blocks(layout_box: &LayoutBox, fonts: &FontDatabase) {
+    match layout_box.box_type {
+        LayoutBoxType::InlineBlockNode => {
+            layout_box.intrinsic_widths(fonts);
+        }
+        _ => layout_box.children.iter().for_each(|child| measure_inline_blocks(child, fonts)),
+    }
+}
+
+// Satır kutusunun içeriğinin yatay hizalaması (`text-align`); start ve end yazı yönüne göre çözülür
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TextAlign {
+    Left,
+    Right,
+    Center,
+    Justify,
+}
+
+impl TextAlign {
+    fn of(node: &StyledNode) -> TextAlign {
+        let rtl = keyword(node, "direction") == "rtl";
+        match keyword(node, "text-align") {
+            "right" => TextAlign::Right,
+            "center" => TextAlign::Center,
+            "justify" => TextAlign::Justify,
+            "end" if !rtl => TextAlign::Right,
+            "start" | "" if rtl => TextAlign::Right,
+            _ => TextAlign::Left,
+        }
+    }
+}
+
+// Satırın içeriğini hizalar; iki yana yaslamada boş alan kelime aralarındaki boşluklara dağıtılır.
+// Satıra sığmayan içerik her zaman satır başına hizalanır.
+fn align_line(line_box: &mut LayoutBox, alignment: TextAlign, fonts: &FontDatabase) {
+    let line = line_box.dimensions.content.clone();
+    let end = line_box.children.last().map_or(line.x, |child| {
+        let margin_box = child.dimensions.margin_box();
+        margin_box.x + margin_box.width
+    });
+    let free_space = line.x + line.width - end;
+    if free_space <= 0.0 {
+        return;
+    }
+    match alignment {
+        TextAlign::Left => {}
+        TextAlign::Right => shift_children(line_box, free_space),
+        TextAlign::Center => shift_children(line_box, free_space / 2.0),
+        TextAlign::Justify => {
+            let spaces = count_spaces(line_box);
+            if spaces > 0 {
+                let mut shift = 0.0;
+                justify_children(line_box, free_space / spaces as f32, &mut shift, fonts);
+            }
+        }
+    }
+}
+
+fn shift_children(layout_box: &mut LayoutBox, dx: f32) {
+    for child in &mut layout_box.children {
+        child.translate(dx, 0.0);
+    }
+}
+
+// Satırdaki kelime ayırıcı boşlukların sayısı
+fn count_spaces(layout_box: &LayoutBox) -> usize {
+    layout_box.children.iter()
+        .map(|child| match &child.box_type {
+            LayoutBoxType::TextFragment(text) => text.matches(' ').count(),
+            LayoutBoxType::InlineBlockNode => 0,
+            _ => count_spaces(child),
+        })
+        .sum()
+}
+
+// Metin parçalarını kelimelere böler ve her boşluktan sonra `gap` kadar boşluk ekler;
+// satır içi kutular içerdikleri boşluklar kadar genişler
+fn justify_children(layout_box: &mut LayoutBox, gap: f32, shift: &mut f32, fonts: &FontDatabase) {
+    let mut children = vec![];
+    for mut child in layout_box.children.drain(..) {
+        if let LayoutBoxType::InlineBlockNode = child.box_type {
+            child.translate(*shift, 0.0);
+            children.push(child);
+            continue;
+        }
+        let LayoutBoxType::TextFragment(text) = &child.box_type else {
+            let start_shift = *shift;
+            child.dimensions.content.x += start_shift;
+            justify_children(&mut child, gap, shift, fonts);
+            child.dimensions.content.width += *shift - start_shift;
+            children.push(child);
+            continue;
+        };
+        let query = FontQuery::from_styled_node(child.get_styled_node());
+        let mut offset = 0;
+        for word in text.split_inclusive(' ') {
+            let mut fragment = LayoutBox::new(LayoutBoxType::TextFragment(word.to_string()));
+            fragment.styled_node = child.styled_node;
+            fragment.dimensions.content = Rect {
+                x: child.dimensions.content.x + fonts.measure(&text[..offset], &query).width + *shift,
+                width: fonts.measure(word, &query).width,
+                ..child.dimensions.content.clone()
+            };
+            children.push(fragment);
+            offset += word.len();
+            if word.ends_with(' ') {
+                *shift += gap;
+            }
+        }
+    }
+    layout_box.children = children;
+}
+
+// Satır içi kutu ağacını düz bir parça listesine çevirir; metin satır kırma fırsatlarından bölünür.
+// Yüzdeler `containing_width` değerine göre çözülür; satır içi bloklar `available_width` içine sığacak şekilde boyutlandırılır.
+fn collect_items<'a>(inline_box: LayoutBox<'a>, containing_width: f32, available_width: f32, fonts: &FontDatabase, items: &mut Vec<InlineItem<'a>>, after_space: &mut bool) {
+    let Some(node) = inline_box.styled_node else { return };
+    match (&inline_box.box_type, &node.node.node_type) {
+        (_, NodeType::Text(text)) => segment_text(node, text, fonts, items, after_space),
+        (LayoutBoxType::InlineBlockNode, _) => {
+            let mut atomic = inline_box;
+            atomic.size_inline_block(containing_width, available_width, fonts);
+            // Satır içi bloğun önünde ve ardında satır kırılabilir
+            if let Some(InlineItem::Text(text)) = items.iter_mut().rev().find(|item| !matches!(item, InlineItem::Start(..) | InlineItem::End(_)))
+                && text.break_after.is_none()
+                && WhiteSpace::of(text.node).wrap {
+                text.break_after = Some(BreakOpportunity::Allowed);
+            }
+            let break_after = WhiteSpace::of(node).wrap.then_some(BreakOpportunity::Allowed);
+            items.push(InlineItem::Atomic(Box::new(atomic), break_after));
+            *after_space = false;
+        }
+        (_, NodeType::Element(_)) => {
+            let edges = inline_box.horizontal_edges(containing_width);
+            items.push(InlineItem::Start(node, edges.left, inline_box.continuation));
+            for child in inline_box.children {
+                collect_items(child, containing_width, available_width, fonts, items, after_space);
+            }
+            items.push(InlineItem::End(edges.right));
+        }
+    }
+}
+
+// Metni beyaz boşluk kurallarına göre hazırlar ve kırma fırsatlarında parçalara böler
+fn segment_text<'a>(node: &'a StyledNode<'a>, text: &str, fonts: &FontDatabase, items: &mut Vec<InlineItem<'a>>, after_space: &mut bool) {
+    let white_space = WhiteSpace::of(node);
+    let text = collapse_white_space(text, white_space, after_space);
+    if text.is_empty() {
+        return;
+    }
+    let query = FontQuery::from_styled_node(node);
+    let word_break = keyword(node, "word-break");
+    let splittable = white_space.wrap && matches!(keyword(node, "overflow-wrap"), "break-word" | "anywhere")
+        || word_break == "break-word";
+
+    let mut breaks: Vec<(usize, BreakOpportunity)> = linebreaks(&text)
+        .filter(|(index, _)| *index < text.len())
+        .filter(|(index, opportunity)| match opportunity {
+            BreakOpportunity::Mandatory => true,
+            // keep-all: harfler arasında kırılmaz, yalnızca boşluk ve noktalama sonrasında kırılır
+            BreakOpportunity::Allowed => white_space.wrap && !(word_break == "keep-all" && between_letters(&text, *index)),
+        })
+        .collect();
+    // break-all: kelimeler her harften sonra kırılabilir
+    if white_space.wrap && word_break == "break-all" {
+        for (index, _) in text.char_indices().skip(1) {
+            if between_letters(&text, index) && !breaks.iter().any(|(existing, _)| *existing == index) {
+                breaks.push((index, BreakOpportunity::Allowed));
+            }
+        }
+        breaks.sort_by_key(|(index, _)| *index);
+    }
+
+    // Metnin sonunda kırma fırsatı yalnızca boşlukla bitiyorsa vardır; sonraki düğümün ilk karakterine bağlıdır
+    let ends_with_break = white_space.wrap && text.ends_with(' ');
+    let mut start = 0;
+    let ends = breaks.into_iter().map(|(index, opportunity)| (index, Some(opportunity)))
+        .chain(std::iter::once((text.len(), ends_with_break.then_some(BreakOpportunity::Allowed))));
+    for (end, break_after) in ends {
+        let segment = &text[start..end];
+        start = end;
+        // Zorunlu kırılmalara yol açan satır sonu karakterleri çizilmez
+        let visible = segment.trim_end_matches('\n');
+        let width = fonts.measure(&visible.replace(SOFT_HYPHEN, ""), &query).width;
+        let end_width = fonts.measure(&line_end_text(visible, white_space), &query).width;
+        items.push(InlineItem::Text(TextItem {
+            node,
+            text: visible.to_string(),
+            width,
+            end_width,
+            break_after,
+            splittable,
+        }));
+    }
+}
+
+// Kırma fırsatının iki harf (ya da rakam) arasında olup olmadığı
+fn between_letters(text: &str, index: usize) -> bool {
+    let before = text[..index].chars().next_back();
+    let after = text[index..].chars().next();
+    before.is_some_and(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric)
+}
+
+// Daraltılabilir boşlukları tek boşluğa indirir; korunmuyorsa satır sonları da boşluk sayılır
+fn collapse_white_space(text: &str, white_space: WhiteSpace, after_space: &mut bool) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '\n' if white_space.preserve_newlines => {
+                // pre-line: satır sonundan önceki boşluklar atılır
+                if white_space.collapse_spaces && collapsed.ends_with(' ') {
+                    collapsed.pop();
+                }
+                collapsed.push('\n');
+                *after_space = true;
+            }
+            '\t' if !white_space.collapse_spaces => collapsed.push_str("        "),
+            ' ' | '\t' | '\n' | '\r' | '\u{C}' if white_space.collapse_spaces => {
+                if !*after_space {
+                    collapsed.push(' ');
+                }
+                *after_space = true;
+            }
+            _ => {
+                collapsed.push(character);
+                *after_space = false;
+            }
+        }
+    }
+    collapsed
+}
+
+// Satır bu parçada biterse çizilen metin: daraltılabilir boşluklar asılı kalır, yumuşak tire görünür olur
+fn line_end_text(segment: &str, white_space: WhiteSpace) -> String {
+    let segment = if white_space.collapse_spaces { segment.trim_end_matches(' ') } else { segment };
+    match segment.strip_suffix(SOFT_HYPHEN) {
+        Some(rest) => format!("{}-", rest.replace(SOFT_HYPHEN, "")),
+        None => segment.replace(SOFT_HYPHEN, ""),
+    }
+}
+
+// Parçaları satırlara dağıtır: kırma fırsatları arasındaki parçalar bölünmeden aynı satıra yerleşir
+// İlk satırın genişliği girinti kadar azdır
+fn break_lines<'a>(items: Vec<InlineItem<'a>>, available_width: f32, first_line_indent: f32, fonts: &FontDatabase) -> Vec<Vec<InlineItem<'a>>> {
+    let mut lines = vec![];
+    let mut line: Vec<InlineItem> = vec![];
+    let mut line_width = 0.0;
+    let mut queue: VecDeque<InlineItem> = items.into();
+    let mut chunk: Vec<InlineItem> = vec![];
+
+    while let Some(item) = queue.pop_front() {
+        let break_after = match &item {
+            InlineItem::Text(text) => text.break_after,
+            InlineItem::Atomic(_, break_after) => *break_after,
+            _ => None,
+        };
+        chunk.push(item);
+        // Sondaki eleman kapanışları parçaya dahildir; kırma fırsatı onlardan sonradır
+        if break_after.is_none() && !queue.is_empty() {
+            continue;
+        }
+        while let Some(InlineItem::End(_)) = queue.front() {
+            chunk.push(queue.pop_front().unwrap());
+        }
+
+        let width: f32 = chunk.iter().map(|item| item_width(item, false)).sum();
+        let end_width = chunk_end_width(&chunk);
+        let available = |lines: &Vec<_>| available_width - if lines.is_empty() { first_line_indent } else { 0.0 };
+        // Sığmayan parça yeni satıra geçer
+        if !line.is_empty() && line_width + end_width > available(&lines) + 0.01 {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0.0;
+        }
+        // Boş satıra bile sığmayan kelime, izin veriliyorsa karakterlerinden bölünür
+        if line.is_empty() && end_width > available(&lines) + 0.01 && can_split(&chunk) {
+            for item in split_chunk(std::mem::take(&mut chunk), fonts).into_iter().rev() {
+                queue.push_front(item);
+            }
+            continue;
+        }
+        line.append(&mut chunk);
+        line_width += width;
+        if break_after == Some(BreakOpportunity::Mandatory) {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0.0;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+// Parçanın satırdaki genişliği; satır sonundaysa asılı boşluklar sayılmaz
+fn item_width(item: &InlineItem, at_line_end: bool) -> f32 {
+    match item {
+        InlineItem::Text(text) if at_line_end => text.end_width,
+        InlineItem::Text(text) => text.width,
+        InlineItem::Start(_, edge, _) | InlineItem::End(edge) => *edge,
+        InlineItem::Atomic(atomic, _) => atomic.dimensions.margin_box().width,
+    }
+}
+
+// Satır bu parçadan sonra biterse parçanın kapladığı genişlik
+fn chunk_end_width(chunk: &[InlineItem]) -> f32 {
+    let last_text = chunk.iter().rposition(|item| matches!(item, InlineItem::Text(_)));
+    chunk.iter().enumerate().map(|(index, item)| item_width(item, Some(index) == last_text)).sum()
+}
+
+fn can_split(chunk: &[InlineItem]) -> bool {
+    chunk.iter().any(|item| matches!(item, InlineItem::Text(text) if text.splittable && text.text.chars().count() > 1))
+}
+
+// Bölünebilir metin parçalarını tek karakterlik parçalara ayırır; her karakterden sonra kırılabilir
+fn split_chunk<'a>(chunk: Vec<InlineItem<'a>>, fonts: &FontDatabase) -> Vec<InlineItem<'a>> {
+    let mut split = vec![];
+    for item in chunk {
+        let InlineItem::Text(text) = item else {
+            split.push(item);
+            continue;
+        };
+        if !text.splittable {
+            split.push(InlineItem::Text(text));
+            continue;
+        }
+        let query = FontQuery::from_styled_node(text.node);
+        // Sondaki boşluklar son karakterle birlikte kalır; ayrı bir parça olsalar tek başlarına satıra geçerlerdi
+        let mut pieces: Vec<String> = vec![];
+        for character in text.text.chars() {
+            match pieces.last_mut() {
+                Some(piece) if character == ' ' => piece.push(character),
+                _ => pieces.push(character.to_string()),
+            }
+        }
+        for (index, content) in pieces.iter().enumerate() {
+            let last = index + 1 == pieces.len();
+            let width = fonts.measure(content, &query).width;
+            split.push(InlineItem::Text(TextItem {
+                node: text.node,
+                end_width: if last { text.end_width - (text.width - width).max(0.0) } else { width },
+                width,
+                text: content.clone(),
+                break_after: if last { text.break_after } else { Some(BreakOpportunity::Allowed) },
+                splittable: false,
+            }));
+        }
+    }
+    split
+}
+
+// Bir satırın parçalarından satır kutusunu kurar. Birden çok satıra yayılan satır içi elemanlar
+// her satırda ayrı bir kutuyla temsil edilir; sol kenarlar ilk, sağ kenarlar son parçadadır.
+fn build_line_box<'a>(line: Vec<InlineItem<'a>>, open: &mut Vec<(&'a StyledNode<'a>, Continuation)>, container_node: Option<&StyledNode>, container: &Rect, y: f32, indent: f32, fonts: &FontDatabase) -> LayoutBox<'a> {
+    let mut line_box = LayoutBox::new(LayoutBoxType::LineBox(y));
+    // Girinti satırın başında ek bir boşluk gibi davranır
+    line_box.dimensions.content = Rect { x: container.x + indent, y, width: container.width - indent, height: 0.0 };
+
+    let last_text = line.iter().rposition(|item| matches!(item, InlineItem::Text(_)));
+    let mut x = container.x + indent;
+    // Açık satır içi kutular; en dıştaki satır kutusunun kendisidir
+    let mut stack: Vec<LayoutBox<'a>> = vec![line_box];
+    for (node, continuation) in open.iter() {
+        stack.push(inline_fragment(node, *continuation, x, container, false));
+    }
+
+    for (index, item) in line.into_iter().enumerate() {
+        match item {
+            InlineItem::Start(node, _, continuation) => {
+                let fragment = inline_fragment(node, continuation, x, container, true);
+                x = fragment.dimensions.content.x;
+                stack.push(fragment);
+                open.push((node, continuation));
+            }
+            InlineItem::End(_) => {
+                let mut fragment = stack.pop().expect("Unbalanced inline boxes");
+                open.pop();
+                close_inline_fragment(&mut fragment, x, true);
+                x = fragment.dimensions.margin_box().x + fragment.dimensions.margin_box().width;
+                stack.last_mut().unwrap().children.push(fragment);
+            }
+            InlineItem::Atomic(mut atomic, _) => {
+                let margin_box = atomic.dimensions.margin_box();
+                atomic.translate(x - margin_box.x, 0.0);
+                x += margin_box.width;
+                stack.last_mut().unwrap().children.push(*atomic);
+            }
+            InlineItem::Text(text) => {
+                let white_space = WhiteSpace::of(text.node);
+                let content = if Some(index) == last_text {
+                    line_end_text(&text.text, white_space)
+                } else {
+                    text.text.replace(SOFT_HYPHEN, "")
+                };
+                let parent = stack.last_mut().unwrap();
+                // Aynı metin düğümünün ardışık parçaları tek parça olarak ölçülür ve çizilir
+                let fragment = match parent.children.last_mut() {
+                    Some(previous) if matches!(previous.box_type, LayoutBoxType::TextFragment(_))
+                        && previous.styled_node.is_some_and(|node| std::ptr::eq(node, text.node)) => previous,
+                    _ => {
+                        let mut fragment = LayoutBox::new(LayoutBoxType::TextFragment(String::new()));
+                        fragment.styled_node = Some(text.node);
+                        fragment.dimensions.content.x = x;
+                        parent.children.push(fragment);
+                        parent.children.last_mut().unwrap()
+                    }
+                };
+                if let LayoutBoxType::TextFragment(existing) = &mut fragment.box_type {
+                    existing.push_str(&content);
+                    fragment.dimensions.content.width = fonts.measure(existing, &FontQuery::from_styled_node(text.node)).width;
+                    x = fragment.dimensions.content.x + fragment.dimensions.content.width;
+                }
+            }
+        }
+    }
+    // Sonraki satıra devam eden kutular sağ kenarları olmadan kapatılır
+    while stack.len() > 1 {
+        let mut fragment = stack.pop().unwrap();
+        close_inline_fragment(&mut fragment, x, false);
+        stack.last_mut().unwrap().children.push(fragment);
+    }
+
+    let mut line_box = stack.pop().unwrap();
+    align_vertically(&mut line_box, container_node, fonts);
+    line_box
+}
+
+// Satır içi elemanın bu satırdaki kutusunu açar; sol kenarlar yalnızca elemanın ilk parçasında bulunur
+fn inline_fragment<'a>(node: &'a StyledNode<'a>, continuation: Continuation, x: f32, container: &Rect, first: bool) -> LayoutBox<'a> {
+    let mut fragment = LayoutBox::new(LayoutBoxType::InlineNode);
+    fragment.styled_node = Some(node);
+    fragment.continuation = continuation;
+    let first = first && !continuation.before;
+    fragment.dimensions.margin = fragment.calculate_edge_sizes("margin", "", container.width, 0.0);
+    fragment.dimensions.padding = fragment.calculate_edge_sizes("padding", "", container.width, 0.0);
+    fragment.dimensions.border = fragment.calculate_edge_sizes("border", "-width", container.width, 0.0);
+    if !first {
+        for edges in [&mut fragment.dimensions.margin, &mut fragment.dimensions.border, &mut fragment.dimensions.padding] {
+            edges.left = 0.0;
+        }
+    }
+    let left = fragment.dimensions.margin.left + fragment.dimensions.border.left + fragment.dimensions.padding.left;
+    fragment.dimensions.content.x = x + left;
+    fragment
+}
+
+// Satır içi kutuyu kapatır: genişliği içeriğinden gelir, sağ kenarlar yalnızca son parçadadır
+fn close_inline_fragment(fragment: &mut LayoutBox, x: f32, last: bool) {
+    if !last || fragment.continuation.after {
+        for edges in [&mut fragment.dimensions.margin, &mut fragment.dimensions.border, &mut fragment.dimensions.padding] {
+            edges.right = 0.0;
+        }
+    }
+    fragment.dimensions.content.width = x - fragment.dimensions.content.x;
+}
+
+// Satırdaki bir kutunun fontundan ve `line-height` değerinden gelen dikey ölçüleri
+#[derive(Debug, Default, Clone, Copy)]
+struct InlineMetrics {
+    ascent: f32,
+    descent: f32,
+    half_leading: f32, // Satır yüksekliği ile içerik alanı arasındaki farkın yarısı; üste ve alta eklenir
+    line_height: f32,
+    font_size: f32,
+}
+
+impl InlineMetrics {
+    fn of(node: &StyledNode, text: &str, fonts: &FontDatabase) -> InlineMetrics {
+        let query = FontQuery::from_styled_node(node);
+        let metrics = fonts.measure(text, &query);
+        let line_height = match node.get_property("line-height") {
+            Some(Value::Number(factor)) => factor * query.size,
+            Some(value) => resolve_length(value, query.size).unwrap_or(metrics.line_height()),
+            None => metrics.line_height(),
+        };
+        InlineMetrics {
+            ascent: metrics.ascent,
+            descent: metrics.descent,
+            half_leading: (line_height - metrics.ascent - metrics.descent) / 2.0,
+            line_height,
+            font_size: query.size,
+        }
+    }
+}
+
+// Kutunun ebeveyninin taban çizgisine göre hizalanması (`vertical-align`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum VerticalAlign {
+    Raise(f32), // Taban çizgisinin ebeveyninkinden ne kadar yukarıda olduğu (baseline, sub, super, uzunluk)
+    Middle,
+    Top,
+    Bottom,
+}
+
+impl VerticalAlign {
+    fn of(fragment: &LayoutBox, metrics: &InlineMetrics, parent: &InlineMetrics) -> VerticalAlign {
+        // Metin parçaları her zaman ebeveyn kutunun taban çizgisindedir
+        if !matches!(fragment.box_type, LayoutBoxType::InlineNode | LayoutBoxType::InlineBlockNode) {
+            return VerticalAlign::Raise(0.0);
+        }
+        match fragment.get_styled_node().get_property("vertical-align") {
+            Some(Value::Keyword(keyword)) => match keyword.as_str() {
+                "middle" => VerticalAlign::Middle,
+                "top" => VerticalAlign::Top,
+                "bottom" => VerticalAlign::Bottom,
+                "sub" => VerticalAlign::Raise(-parent.font_size / 5.0),
+                "super" => VerticalAlign::Raise(parent.font_size / 3.0),
+                _ => VerticalAlign::Raise(0.0),
+            },
+            // Yüzdeler elemanın kendi satır yüksekliğine göredir
+            Some(value) => VerticalAlign::Raise(resolve_length(value, metrics.line_height).unwrap_or(0.0)),
+            None => VerticalAlign::Raise(0.0),
+        }
+    }
+
+    // Satırın üstüne ya da altına hizalanan kutular taban çizgisi hizalamasına katılmaz
+    fn is_line_relative(fragment: &LayoutBox) -> bool {
+        matches!(fragment.box_type, LayoutBoxType::InlineNode | LayoutBoxType::InlineBlockNode)
+            && matches!(fragment.get_styled_node().get_property("vertical-align"), Some(Value::Keyword(keyword)) if keyword == "top" || keyword == "bottom")
+    }
+}
+
+// Satırdaki kutuları taban çizgilerine göre dikeyde yerleştirir ve satır yüksekliğini belirler (CSS 2.1 §10.8).
+// Kutuların içerik alanı fontun yükselim ve inişinden, satırda kapladıkları yükseklik `line-height` değerinden gelir.
+// Kabın fontu, satırın her zaman içerdiği görünmez bir "strut" olarak sayılır.
+fn align_vertically(line_box: &mut LayoutBox, container_node: Option<&StyledNode>, fonts: &FontDatabase) {
+    let strut = container_node.map_or_else(InlineMetrics::default, |node| InlineMetrics::of(node, "", fonts));
+    let mut extent = (-strut.ascent - strut.half_leading, strut.descent + strut.half_leading);
+    // Önce her şey satırın taban çizgisine (0) göre yerleşir; üst ve alt hizalı kutular kendi taban çizgilerine göre
+    let mut detached = VecDeque::new();
+    let aligned = align_baselines(line_box, 0.0, &strut, fonts, &mut detached);
+    extent = (extent.0.min(aligned.0), extent.1.max(aligned.1));
+
+    let line_height = detached.iter()
+        .map(|(_, (top, bottom))| bottom - top)
+        .fold(extent.1 - extent.0, f32::max);
+    let line_y = line_box.dimensions.content.y;
+    line_box.dimensions.content.height = line_height;
+    line_box.box_type = LayoutBoxType::LineBox(line_y - extent.0);
+    // Sonra göreli konumlar satırın mutlak konumuna taşınır
+    place_vertically(line_box, line_y - extent.0, line_y, line_height, &mut detached);
+}
+
+// Çocukları verilen taban çizgisine göre yerleştirir; taban çizgisine bağlı kutuların kapladığı dikey aralığı döndürür.
+// Üst ve alt hizalı kutuların aralıkları, ağaçtaki sıralarıyla `detached` listesine eklenir.
+fn align_baselines(layout_box: &mut LayoutBox, baseline: f32, parent: &InlineMetrics, fonts: &FontDatabase, detached: &mut VecDeque<(VerticalAlign, (f32, f32))>) -> (f32, f32) {
+    let mut extent = (f32::INFINITY, f32::NEG_INFINITY);
+    for child in &mut layout_box.children {
+        let text = match &child.box_type {
+            LayoutBoxType::TextFragment(text) => text.as_str(),
+            _ => "",
+        };
+        let mut metrics = InlineMetrics::of(child.get_styled_node(), text, fonts);
+        let atomic = matches!(child.box_type, LayoutBoxType::InlineBlockNode);
+        if atomic {
+            // Satır içi bloğun taban çizgisi son satırınınkidir; satırı yoksa alt kenar boşluğu kenarıdır.
+            // Satırda kenar boşluğu kutusu kadar yer kaplar.
+            let margin_box = child.dimensions.margin_box();
+            let baseline_offset = child.last_baseline().map_or(margin_box.height, |baseline| baseline - margin_box.y);
+            metrics = InlineMetrics { ascent: baseline_offset, descent: margin_box.height - baseline_offset, half_leading: 0.0, ..metrics };
+        }
+        let alignment = VerticalAlign::of(child, &metrics, parent);
+        let child_baseline = match alignment {
+            VerticalAlign::Raise(raise) => baseline - raise,
+            // Kutunun ortası ebeveynin taban çizgisinden x yüksekliğinin yarısı kadar yukarıdadır (x yüksekliği ~0.5em)
+            VerticalAlign::Middle => baseline - parent.font_size / 4.0 + (metrics.ascent - metrics.descent) / 2.0,
+            VerticalAlign::Top | VerticalAlign::Bottom => 0.0,
+        };
+        let index = detached.len();
+        let line_relative = matches!(alignment, VerticalAlign::Top | VerticalAlign::Bottom);
+        if line_relative {
+            detached.push_back((alignment, (0.0, 0.0)));
+        }
+
+        let own = (child_baseline - metrics.ascent - metrics.half_leading, child_baseline + metrics.descent + metrics.half_leading);
+        let child_extent = if atomic {
+            let margin_top = child.dimensions.margin_box().y;
+            child.translate(0.0, child_baseline - metrics.ascent - margin_top);
+            own
+        } else {
+            child.dimensions.content.y = child_baseline - metrics.ascent;
+            child.dimensions.content.height = metrics.ascent + metrics.descent;
+            let nested = align_baselines(child, child_baseline, &metrics, fonts, detached);
+            (own.0.min(nested.0), own.1.max(nested.1))
+        };
+
+        if line_relative {
+            detached[index].1 = child_extent;
+        } else {
+            extent = (extent.0.min(child_extent.0), extent.1.max(child_extent.1));
+        }
+    }
+    extent
+}
+
+// Göreli y konumlarını `dy` kadar kaydırır; üst ve alt hizalı kutular satırın üstüne ya da altına taşınır
+fn place_vertically(layout_box: &mut LayoutBox, dy: f32, line_y: f32, line_height: f32, detached: &mut VecDeque<(VerticalAlign, (f32, f32))>) {
+    for child in &mut layout_box.children {
+        let child_dy = match VerticalAlign::is_line_relative(child).then(|| detached.pop_front()).flatten() {
+            Some((VerticalAlign::Top, (top, _))) => line_y - top,
+            Some((_, (_, bottom))) => line_y + line_height - bottom,
+            None => dy,
+        };
+        if let LayoutBoxType::InlineBlockNode = child.box_type {
+            child.translate(0.0, child_dy);
+        } else {
+            child.dimensions.content.y += child_dy;
+            place_vertically(child, child_dy, line_y, line_height, detached);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use crate::css::media::MediaEnvironment;
+    use crate::css::parser::Parser as CssParser;
+    use crate::html::enums::AttrName;
+    use crate::html::parser::Parser as HtmlParser;
+    use crate::layout::structs::Dimensions;
+    use crate::layout::tree::build_layout_tree;
+    use crate::style::structs::style_tree;
+
+    // Yerleştirilmiş bir satır kutusu ve içindeki metin parçaları
+    struct Line {
+        rect: Rect,
+        baseline: f32,
+        texts: Vec<(String, Rect)>,
+    }
+
+    impl Line {
+        fn text(&self) -> String {
+            self.texts.iter().map(|(text, _)| text.as_str()).collect()
+        }
+    }
+
+    // Belgeyi 800x600 görüntü alanında yerleştirir. Satır kutularını ağaç sırasıyla ve `id` özniteliği olan
+    // kutuların boyutlarını döndürür; satırlara bölünen satır içi elemanların her parçası ayrı yer alır.
+    fn layout(html: &str, css: &str) -> (Vec<Line>, HashMap<String, Vec<Dimensions>>) {
+        let dom = HtmlParser::new(html.to_string()).parse();
+        let stylesheets = vec![CssParser::new(css.to_string()).parse()];
+        let mut viewport = Dimensions::default();
+        viewport.content.width = 800.0;
+        viewport.content.height = 600.0;
+        let root = style_tree(&dom, &stylesheets, &viewport, &MediaEnvironment::default());
+        let mut layout_root = build_layout_tree(&root);
+        layout_root.layout(viewport, &FontDatabase::default());
+
+        // Satır içi blokların metni kendi satırlarına aittir
+        fn collect_texts(layout_box: &LayoutBox, texts: &mut Vec<(String, Rect)>) {
+            for child in &layout_box.children {
+                match &child.box_type {
+                    LayoutBoxType::TextFragment(text) => texts.push((text.clone(), child.dimensions.content.clone())),
+                    LayoutBoxType::InlineBlockNode => {}
+                    _ => collect_texts(child, texts),
+                }
+            }
+        }
+        fn collect(layout_box: &LayoutBox, lines: &mut Vec<Line>, boxes: &mut HashMap<String, Vec<Dimensions>>) {
+            if let LayoutBoxType::LineBox(baseline) = layout_box.box_type {
+                let mut texts = vec![];
+                collect_texts(layout_box, &mut texts);
+                lines.push(Line { rect: layout_box.dimensions.content.clone(), baseline, texts });
+            }
+            if let Some(styled_node) = layout_box.styled_node
+                && let NodeType::Element(element) = &styled_node.node.node_type
+                && let Some(id) = element.attributes.get(&AttrName::Id) {
+                boxes.entry(id.clone()).or_default().push(layout_box.dimensions.clone());
+            }
+            layout_box.children.iter().for_each(|child| collect(child, lines, boxes));
+        }
+        let (mut lines, mut boxes) = (vec![], HashMap::new());
+        collect(&layout_root, &mut lines, &mut boxes);
+        (lines, boxes)
+    }
+
+    // Her satırın metni
+    fn line_texts(html: &str, css: &str) -> Vec<String> {
+        layout(html, css).0.iter().map(Line::text).collect()
+    }
+
+    #[test]
+    fn lines_break_at_unicode_break_opportunities() {
+        // Sıfır genişlikte her kırma fırsatında yeni satıra geçilir; satır sonundaki boşluklar asılı kalır
+        assert_eq!(line_texts("<div>aaa bbb-ccc (ddd)</div>", "div { width: 0 }"), vec!["aaa", "bbb-", "ccc", "(ddd)"]);
+        assert_eq!(line_texts("<div>漢字かな</div>", "div { width: 0 }"), vec!["漢", "字", "か", "な"]);
+        assert_eq!(line_texts("<div>aaa bbb ccc</div>", "div { width: 800px }"), vec!["aaa bbb ccc"]);
+        // Satırlar kabın içeriğinde alt alta dizilir
+        let (lines, boxes) = layout("<div id=\"c\">aaa bbb</div>", "div { width: 0; padding: 5px }");
+        let container = &boxes["c"][0].content;
+        assert_eq!((lines[0].rect.x, lines[0].rect.y), (container.x, container.y));
+        assert_eq!(lines[1].rect.y, lines[0].rect.y + lines[0].rect.height);
+        assert_eq!(container.height, lines[0].rect.height + lines[1].rect.height);
+        // Kırma fırsatı elemanların sınırında da aranır; elemanlar arasındaki boşluklar tek boşluğa iner
+        assert_eq!(line_texts("<div>aa<b>bb </b> cc</div>", "div { width: 0 }"), vec!["aabb", "cc"]);
+        assert_eq!(line_texts("<div>aa <b> bb</b></div>", "div { width: 800px }"), vec!["aa bb"]);
+        // Yumuşak tire yalnızca satır orada kırılırsa görünür
+        assert_eq!(line_texts("<div>ab\u{AD}cd</div>", "div { width: 0 }"), vec!["ab-", "cd"]);
+        assert_eq!(line_texts("<div>ab\u{AD}cd</div>", "div { width: 800px }"), vec!["abcd"]);
+    }
+
+    #[test]
+    fn white_space_controls_collapsing_and_wrapping() {
+        let html = "<div>a  b\n c  </div>";
+        assert_eq!(line_texts(html, "div { width: 0 }"), vec!["a", "b", "c"]);
+        assert_eq!(line_texts(html, "div { width: 0; white-space: nowrap }"), vec!["a b c"]);
+        assert_eq!(line_texts(html, "div { width: 0; white-space: pre }"), vec!["a  b", " c  "]);
+        assert_eq!(line_texts(html, "div { width: 0; white-space: pre-line }"), vec!["a", "b", "c"]);
+        assert_eq!(line_texts(html, "div { width: 800px; white-space: pre-line }"), vec!["a b", "c"]);
+        assert_eq!(line_texts(html, "div { width: 800px; white-space: pre-wrap }"), vec!["a  b", " c  "]);
+        // Kalıtılan değer satır içi elemanlarda değiştirilebilir
+        assert_eq!(line_texts("<div>a b <span>c d</span></div>", "div { width: 0 } span { white-space: nowrap }"), vec!["a", "b", "c d"]);
+    }
+
+    #[test]
+    fn word_break_and_overflow_wrap_allow_breaking_inside_words() {
+        assert_eq!(line_texts("<div>abc de</div>", "div { width: 0; word-break: break-all }"), vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(line_texts("<div>漢字 かな</div>", "div { width: 0; word-break: keep-all }"), vec!["漢字", "かな"]);
+        // Satıra sığmayan kelime yalnızca overflow-wrap izin verirse bölünür
+        assert_eq!(line_texts("<div>abc de</div>", "div { width: 0 }"), vec!["abc", "de"]);
+        assert_eq!(line_texts("<div>abc de</div>", "div { width: 0; overflow-wrap: anywhere }"), vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(line_texts("<div>abc de</div>", "div { width: 0; word-break: break-word }"), vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(line_texts("<div>abc de</div>", "div { width: 0; overflow-wrap: break-word; white-space: nowrap }"), vec!["abc de"]);
+        // Bölünen kelime satırı doldurur; sığan kelimeler bölünmez
+        let fonts = FontDatabase::default();
+        let query = FontQuery { families: vec![], weight: 400.0, style: Default::default(), stretch: 100.0, size: 16.0 };
+        let width = fonts.measure("aaa", &query).width + 0.1;
+        let css = format!("div {{ width: {}px; font-size: 16px; overflow-wrap: break-word }}", width);
+        assert_eq!(line_texts("<div>aaaaaaa a</div>", &css), vec!["aaa", "aaa", "a a"]);
+    }
+
+    // Satırdaki metnin sol ve sağ kenarı
+    fn text_extent(line: &Line) -> (f32, f32) {
+        let first = &line.texts.first().unwrap().1;
+        let last = &line.texts.last().unwrap().1;
+        (first.x, last.x + last.width)
+    }
+
+    #[test]
+    fn text_align_places_lines_in_the_container() {
+        let html = "<div id=\"c\">aaa bbb</div>";
+        let align = |css: &str| {
+            let (lines, boxes) = layout(html, &format!("div {{ width: 200px; padding-left: 10px; {} }}", css));
+            let container = boxes["c"][0].content.clone();
+            let (left, right) = text_extent(&lines[0]);
+            (left - container.x, container.x + container.width - right)
+        };
+        let (left, right) = align("");
+        assert_eq!(left, 0.0);
+        assert!(right > 0.0);
+        assert_eq!(align("text-align: right"), (right, 0.0));
+        assert_eq!(align("text-align: center"), (right / 2.0, right / 2.0));
+        // start ve end yazı yönüne göre çözülür
+        assert_eq!(align("text-align: start"), (0.0, right));
+        assert_eq!(align("text-align: end"), (right, 0.0));
+        assert_eq!(align("direction: rtl"), (right, 0.0));
+        assert_eq!(align("direction: rtl; text-align: end"), (0.0, right));
+        assert_eq!(align("direction: rtl; text-align: left"), (0.0, right));
+        // Kabın taşan içeriği her zaman satır başına hizalanır
+        let (lines, boxes) = layout(html, "div { width: 10px; white-space: nowrap; text-align: right }");
+        assert_eq!(text_extent(&lines[0]).0, boxes["c"][0].content.x);
+    }
+
+    #[test]
+    fn justified_lines_spread_words_except_the_last_line() {
+        let html = "<div id=\"c\">aa bb cc dd ee ff gg hh ii jj kk\nll mm</div>";
+        let (lines, boxes) = layout(html, "div { width: 120px; text-align: justify; white-space: pre-line }");
+        let container = boxes["c"][0].content.clone();
+        assert!(lines.len() >= 3);
+        let full_lines = &lines[..lines.len() - 2];
+        for line in full_lines {
+            let (left, right) = text_extent(line);
+            assert_eq!(left, container.x);
+            assert!((right - (container.x + container.width)).abs() < 0.01, "{} {:?}", line.text(), line.texts);
+        }
+        // Zorunlu kırılmayla biten satır ve son satır sola hizalanır; kelime aralıkları değişmez
+        for line in &lines[lines.len() - 2..] {
+            let (left, right) = text_extent(line);
+            assert_eq!(left, container.x);
+            assert!(right < container.x + container.width - 1.0);
+        }
+        // Tek kelimelik satırda dağıtılacak boşluk yoktur
+        let (lines, _) = layout("<div>aaaaaa bb</div>", "div { width: 1px; text-align: justify }");
+        assert_eq!(text_extent(&lines[0]).0, 0.0);
+    }
+
+    #[test]
+    fn text_indent_shifts_only_the_first_line() {
+        let html = "<div id=\"c\">aa bb<p>cc dd</p>ee</div>";
+        let (lines, boxes) = layout(html, "div { width: 0; text-indent: 20px } p { margin: 0 }");
+        let x = boxes["c"][0].content.x;
+        let starts: Vec<f32> = lines.iter().map(|line| text_extent(line).0 - x).collect();
+        // Paragraf girintiyi kalıtır; bloktan sonraki anonim bloğun ilk satırı kabın ilk satırı değildir
+        assert_eq!(starts, vec![20.0, 0.0, 20.0, 0.0, 0.0]);
+        let (lines, _) = layout("<div>aa bb</div>", "div { width: 200px; text-indent: 10%; text-align: right }");
+        assert_eq!(lines[0].rect.x, 20.0);
+        assert_eq!(lines[0].rect.width, 180.0);
+        let (lines, _) = layout("<div>aa</div>", "div { width: 200px; text-indent: -5px }");
+        assert_eq!(text_extent(&lines[0]).0, -5.0);
+    }
+
+    // Gömülü varsayılan fontun verilen boyuttaki yükselim ve inişi
+    fn font_metrics(size: f32) -> (f32, f32, f32) {
+        let query = FontQuery { families: vec![], weight: 400.0, style: Default::default(), stretch: 100.0, size };
+        let metrics = FontDatabase::default().measure("", &query);
+        (metrics.ascent, metrics.descent, metrics.line_gap)
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 0.01, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn line_height_adds_half_leading_above_and_below_the_text() {
+        let (ascent, descent, line_gap) = font_metrics(16.0);
+        let (lines, _) = layout("<div>ab</div>", "div { font-size: 16px; line-height: 40px }");
+        let line = &lines[0];
+        let text = &line.texts[0].1;
+        let half_leading = (40.0 - ascent - descent) / 2.0;
+        assert_eq!(line.rect.height, 40.0);
+        assert_close(text.height, ascent + descent);
+        assert_close(text.y - line.rect.y, half_leading);
+        assert_close(line.baseline, line.rect.y + half_leading + ascent);
+
+        let line_height = |css: &str| layout("<div><p>ab</p></div>", &format!("p {{ margin: 0; font-size: 16px }} {}", css)).0[0].rect.height;
+        assert_close(line_height(""), ascent + descent + line_gap);
+        assert_close(line_height("div { line-height: normal }"), ascent + descent + line_gap);
+        assert_eq!(line_height("p { line-height: 2 }"), 32.0);
+        assert_eq!(line_height("p { line-height: 150% }"), 24.0);
+        assert_eq!(line_height("p { line-height: 1.5em }"), 24.0);
+        // Sayı katsayı olarak kalıtılır; yüzde ve uzunluk ebeveynin boyutuna göre hesaplanıp kalıtılır
+        assert_eq!(line_height("div { font-size: 8px; line-height: 2 }"), 32.0);
+        assert_eq!(line_height("div { font-size: 8px; line-height: 200% }"), 16.0);
+        assert_eq!(line_height("div { font-size: 8px; line-height: 2em }"), 16.0);
+    }
+
+    #[test]
+    fn vertical_align_raises_and_lowers_inline_boxes() {
+        let (ascent, descent, _) = font_metrics(16.0);
+        let aligned = |css: &str| {
+            let (lines, _) = layout("<div>x<span>y</span></div>", &format!("div {{ font-size: 16px; line-height: 20px }} span {{ {} }}", css));
+            let line = &lines[0];
+            let span = &line.texts[1].1;
+            // Metnin taban çizgisinin satırınkinden ne kadar yukarıda olduğu ve satırın yüksekliği
+            (line.baseline - (span.y + ascent), line.rect.height)
+        };
+        assert_eq!(aligned(""), (0.0, 20.0));
+        assert_eq!(aligned("vertical-align: baseline").0, 0.0);
+        let (raise, height) = aligned("vertical-align: super");
+        assert_close(raise, 16.0 / 3.0);
+        assert_close(height, 20.0 + 16.0 / 3.0);
+        assert_close(aligned("vertical-align: sub").0, -16.0 / 5.0);
+        assert_close(aligned("vertical-align: 10px").0, 10.0);
+        assert_close(aligned("vertical-align: -0.5em").0, -8.0);
+        // Yüzdeler elemanın kendi satır yüksekliğine göredir
+        assert_close(aligned("vertical-align: 50%").0, 10.0);
+        assert_close(aligned("line-height: 40px; vertical-align: -25%").0, -10.0);
+
+        // Üste ve alta hizalanan kutular satırın kenarlarına yapışır; satır onları içerecek kadar büyür
+        let (lines, _) = layout("<div>x<span>y</span></div>", "div { font-size: 16px; line-height: 20px } span { line-height: 40px; vertical-align: top }");
+        let line = &lines[0];
+        assert_eq!(line.rect.height, 40.0);
+        assert_close(line.texts[1].1.y, line.rect.y + (40.0 - ascent - descent) / 2.0);
+        assert_close(line.texts[0].1.y, line.rect.y + (20.0 - ascent - descent) / 2.0);
+        let (lines, _) = layout("<div>x<span>y</span></div>", "div { font-size: 16px; line-height: 20px } span { line-height: 40px; vertical-align: bottom }");
+        let line = &lines[0];
+        assert_eq!(line.rect.height, 40.0);
+        assert_close(line.texts[1].1.y + ascent + descent + (40.0 - ascent - descent) / 2.0, line.rect.y + 40.0);
+        // Taban çizgisine hizalanan içerik satırın üstünde kalır
+        assert_close(line.texts[0].1.y, line.rect.y + (20.0 - ascent - descent) / 2.0);
+
+        // middle: kutunun ortası ebeveynin taban çizgisinin x yüksekliğinin yarısı kadar üstündedir
+        let (lines, boxes) = layout("<div>x<span id=\"b\"></span></div>", "div { font-size: 16px } span { display: inline-block; width: 10px; height: 30px; vertical-align: middle }");
+        let block = &boxes["b"][0].content;
+        assert_close(block.y + 15.0, lines[0].baseline - 4.0);
+    }
+
+    #[test]
+    fn display_none_removes_subtrees_and_hidden_boxes_keep_their_space() {
+        let css = "div { width: 800px } #n { display: none } #h { visibility: hidden; padding-left: 10px }";
+        let (lines, boxes) = layout("<div>aa <span id=\"n\">bb<span id=\"m\">cc</span></span>dd<p id=\"p\">ee</p></div>", &format!("{} #p {{ display: none }}", css));
+        // Gizli alt ağacın kutusu ve metni yoktur; ardından gelen metin onun yerini alır
+        assert!(!boxes.contains_key("n") && !boxes.contains_key("m") && !boxes.contains_key("p"));
+        assert_eq!(lines.iter().map(Line::text).collect::<Vec<_>>(), vec!["aa dd"]);
+
+        let (visible, _) = layout("<div>aa<span>bb</span>cc</div>", css);
+        let (hidden, boxes) = layout("<div>aa<span id=\"h\">bb</span>cc</div>", css);
+        // Görünmez kutu yer kaplamaya devam eder
+        assert_eq!(hidden[0].text(), "aabbcc");
+        let hidden_box = &boxes["h"][0];
+        assert_eq!(hidden_box.padding.left, 10.0);
+        assert_eq!(text_extent(&hidden[0]).1, text_extent(&visible[0]).1 + 10.0);
+        let (lines, boxes) = layout("<div><p id=\"h\">aa</p>bb</div>", "#h { visibility: hidden; margin: 0 }");
+        assert_eq!(lines[1].rect.y, boxes["h"][0].content.height);
+    }
+
+    #[test]
+    fn inline_content_around_blocks_goes_into_anonymous_blocks() {
+        let (lines, boxes) = layout("<div id=\"c\">aa<p id=\"p\">bb</p>cc</div>", "div { width: 800px } p { margin: 10px 0 }");
+        assert_eq!(lines.iter().map(Line::text).collect::<Vec<_>>(), vec!["aa", "bb", "cc"]);
+        let container = &boxes["c"][0].content;
+        let paragraph = &boxes["p"][0].content;
+        // Anonim bloklar paragrafın üstünde ve altında yer alır; kenar boşlukları onlarla birleşmez
+        assert_eq!(lines[0].rect.y, container.y);
+        assert_eq!(paragraph.y, lines[0].rect.y + lines[0].rect.height + 10.0);
+        assert_eq!(lines[2].rect.y, paragraph.y + paragraph.height + 10.0);
+        assert_eq!(container.height, lines[2].rect.y + lines[2].rect.height - container.y);
+    }
+
+    #[test]
+    fn inline_boxes_split_around_blocks() {
+        let css = "div { width: 800px } span { padding: 0 5px; border-left: 2px solid red } p { margin: 0 }";
+        let (lines, boxes) = layout("<div>x<span id=\"s\">aa<p id=\"p\">bb</p>cc</span>y</div>", css);
+        assert_eq!(lines.iter().map(Line::text).collect::<Vec<_>>(), vec!["xaa", "bb", "ccy"]);
+        // Bölünen elemanın sol kenarları ilk, sağ kenarları son parçasında kalır
+        let parts = &boxes["s"];
+        assert_eq!(parts.len(), 2);
+        assert_eq!((parts[0].padding.left, parts[0].border.left, parts[0].padding.right), (5.0, 2.0, 0.0));
+        assert_eq!((parts[1].padding.left, parts[1].border.left, parts[1].padding.right), (0.0, 0.0, 5.0));
+        assert_eq!(lines[0].texts[1].1.x, text_extent(&lines[0]).0 + lines[0].texts[0].1.width + 7.0);
+        assert_eq!(lines[2].texts[0].1.x, lines[2].rect.x);
+        // Blok, bölünen elemanın stilini kalıtır
+        assert_eq!(boxes["p"][0].content.y, lines[1].rect.y);
+        assert_eq!(boxes["p"][0].padding.left, 0.0);
+    }
+
+    #[test]
+    fn inline_blocks_shrink_to_fit_and_sit_on_the_baseline() {
+        let fonts = FontDatabase::default();
+        let query = FontQuery { families: vec![], weight: 400.0, style: Default::default(), stretch: 100.0, size: 16.0 };
+        let width = |text: &str| fonts.measure(text, &query).width;
+        let shrink = |container: &str| {
+            let css = format!("div {{ width: {}; font-size: 16px }} span {{ display: inline-block; padding: 0 3px }}", container);
+            layout("<div>x<span id=\"b\">aaa bb</span></div>", &css).1["b"][0].content.width
+        };
+        // Kullanılabilir genişlik en küçük ve en büyük içerik genişliği arasında kalır
+        assert_close(shrink("800px"), width("aaa bb"));
+        assert_close(shrink("0"), width("aaa"));
+        assert_close(shrink(&format!("{}px", width("aaa b") + 6.0)), width("aaa b"));
+
+        // Satır içi bloğun taban çizgisi son satırınınkidir; satırı yoksa alt kenar boşluğu kenarıdır
+        let (lines, _) = layout("<div>x<span id=\"b\">aa<p>bb</p></span></div>", "div { width: 800px; font-size: 16px } span { display: inline-block; font-size: 32px } p { margin: 0 }");
+        let block_lines: Vec<&Line> = lines.iter().filter(|line| line.text() == "bb").collect();
+        assert_close(block_lines[0].baseline, lines[0].baseline);
+        let (lines, boxes) = layout("<div>x<span id=\"b\"></span></div>", "div { font-size: 16px } span { display: inline-block; width: 10px; height: 30px; margin-bottom: 4px }");
+        let block = &boxes["b"][0].content;
+        assert_close(block.y + block.height + 4.0, lines[0].baseline);
+        // Satır içi blok satıra bölünmeden yerleşir; sığmazsa bir sonraki satıra geçer
+        let (lines, _) = layout("<div>aa <span>bb cc</span> dd</div>", "div { width: 60px } span { display: inline-block }");
+        assert_eq!(lines[0].text(), "aa");
+    }
+}
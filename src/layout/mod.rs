@@ -1,2 +1,4 @@
 pub mod enums;
+pub mod inline;
 pub mod structs;
+pub mod tree;
@@ -2,17 +2,73 @@
 //!
 //! This module handles the layout calculation for styled nodes.
 
+use std::cell::OnceCell;
 use crate::{css::enums::Unit, style::structs::StyledNode};
 use super::enums::LayoutBoxType;
-use crate::css::enums::Value;
+use crate::css::enums::{Display, Value};
+use crate::font::structs::FontDatabase;
+use super::inline::{inline_content_widths, layout_lines};
 
 /// Represents a layout box in the render tree
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LayoutBox<'a> {
     pub dimensions: Dimensions,
     pub box_type: LayoutBoxType,
     pub children: Vec<LayoutBox<'a>>,
-    pub styled_node: Option<&'a StyledNode<'a>>, // Stil ağacındaki ilgili düğüme referans
+    pub styled_node: Option<&'a StyledNode<'a>>, // Stil ağacındaki ilgili düğüme referans; anonim kutularda yok
+    pub continuation: Continuation,
+    content_widths: OnceCell<(f32, f32)>, // İlk ölçümde hesaplanan min-content ve max-content genişlikleri
+}
+
+/// Marks the sides of an inline box split around block-level boxes (CSS 2.1 §9.2.1.1).
+/// Margins, borders and padding are dropped on a side where another part of the element continues.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Continuation {
+    pub before: bool,
+    pub after: bool,
+}
+
+/// A set of adjoining vertical margins that collapse into one (CSS 2.1 §8.3.1): the result is the
+/// largest positive margin plus the most negative one
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CollapsedMargin {
+    pub positive: f32,
+    pub negative: f32,
+}
+
+impl CollapsedMargin {
+    // Yeni bir kenar boşluğunu birleşen kenar boşlukları kümesine ekler
+    pub fn adjoin(&mut self, margin: f32) {
+        self.positive = self.positive.max(margin);
+        self.negative = self.negative.min(margin);
+    }
+
+    pub fn resolve(&self) -> f32 {
+        self.positive + self.negative
+    }
+}
+
+/// Margin collapsing state of a block formatting context while block-level boxes are placed
+/// one after another in normal flow
+#[derive(Debug, Clone)]
+pub struct MarginFlow {
+    pub y: f32, // Son yerleştirilen içeriğin altı; bekleyen kenar boşlukları buradan başlar
+    pub margin: CollapsedMargin, // Henüz bir içerik, kenarlık ya da dolguyla ayrılmamış kenar boşlukları
+    pub top: Option<f32>, // Bekleyen kenar boşluklarının ilk çözüldüğü konum; kabın içeriği burada başlar
+}
+
+impl MarginFlow {
+    pub fn new(y: f32) -> MarginFlow {
+        MarginFlow { y, margin: CollapsedMargin::default(), top: Some(y) }
+    }
+
+    /// Applies the pending margins before in-flow content and returns the content's top
+    pub fn resolve(&mut self) -> f32 {
+        self.y += self.margin.resolve();
+        self.margin = CollapsedMargin::default();
+        self.top.get_or_insert(self.y);
+        self.y
+    }
 }
 
 /// Represents the dimensions of a layout box including content, padding, border, and margin
@@ -78,6 +134,8 @@ impl<'a> LayoutBox<'a> {
             box_type,
             children: Vec::new(),
             styled_node: None,
+            continuation: Continuation::default(),
+            content_widths: OnceCell::new(),
         }
     }
 
@@ -85,335 +143,663 @@ impl<'a> LayoutBox<'a> {
         self.styled_node.expect("LayoutBox without a styled node")
     }
 
-    // Bu metod daha sonra kullanılacak, şimdilik sadece tanımlıyoruz
-    pub fn layout(&mut self, containing_block: Dimensions) {
+    // Kutuyu ve çocuklarını kapsayan bloğa göre yerleştirir; metin, boyanırken kullanılan fontlarla ölçülür
+    pub fn layout(&mut self, containing_block: Dimensions, fonts: &FontDatabase) {
         match self.box_type {
-            LayoutBoxType::BlockNode => self.layout_block(containing_block),
-            LayoutBoxType::InlineNode => self.layout_inline(containing_block),
-            LayoutBoxType::AnonymousBlock => self.layout_inline(containing_block),
-            LayoutBoxType::LineBox => self.layout_line_box(containing_block),
+            // Kök kutu yeni bir blok biçimlendirme bağlamı kurar; kenar boşlukları çocuklarınkiyle birleşmez
+            LayoutBoxType::BlockNode => {
+                let mut flow = MarginFlow::new(containing_block.content.y);
+                self.layout_block(&containing_block.content, Some(containing_block.content.height), &mut flow, true, fonts);
+            }
+            LayoutBoxType::InlineBlockNode => self.layout_inline_block(containing_block.content.width, containing_block.content.width, fonts),
+            // Anonim bloklar ve satır içi içerik, kapsayan blokları tarafından yerleştirilir
+            _ => {}
         }
     }
 
-    // Stil ağacındaki bir özelliği Value olarak döndürür
+    // Stil ağacındaki bir özelliğin hesaplanmış değerini döndürür; anonim kutuların kendi özellikleri yoktur
     fn get_property(&self, name: &str) -> Option<&Value> {
-        self.get_styled_node().specified_values.get(name)
+        self.styled_node?.get_property(name)
     }
 
-    // CSS Value'den bir piksel değeri alır, yoksa varsayılanı döndürür
-    fn get_float_value(&self, name: &str, default: f32) -> f32 {
-        // Önce spesifik özelliği ara (örn: "margin-top")
-        if let Some(&Value::Length(f, _)) = self.get_property(name) {
-            return f;
-        }
+    // CSS Value'den bir piksel değeri alır; yüzdeler verilen referans uzunluğa göre çözülür
+    fn get_length_value(&self, name: &str, reference: f32, default: f32) -> f32 {
+        self.get_property(name)
+            .and_then(|value| resolve_length(value, reference))
+            .unwrap_or(default)
+    }
 
-        // Eğer spesifik özellik bulunamazsa, genel özelliği ara (örn: "margin")
-        let general_name = name.split('-').next().unwrap_or(name);
-        if general_name != name { // Eğer spesifik bir özellik arıyorsak ve genel isim farklıysa
-            if let Some(&Value::Length(f, _)) = self.get_property(general_name) {
-                return f;
-            }
+    /// Computes EdgeSizes from the longhand properties (e.g. "margin-top", "border-top-width");
+    /// percentages refer to the containing block's width
+    pub fn calculate_edge_sizes(&self, prefix: &str, suffix: &str, containing_width: f32, default: f32) -> EdgeSizes {
+        let side = |name: &str| self.get_length_value(&format!("{}-{}{}", prefix, name, suffix), containing_width, default);
+        EdgeSizes {
+            top: side("top"),
+            bottom: side("bottom"),
+            left: side("left"),
+            right: side("right"),
         }
-        default
     }
 
-    // EdgeSizes'ı stil özelliklerinden hesaplar
-    fn calculate_edge_sizes(&self, prefix: &str, default: f32) -> EdgeSizes {
-        let all = self.get_float_value(prefix, default); // Genel değeri al
-
+    /// Returns the total left and right edges (margin + border + padding) of the box;
+    /// sides continued by another part of a split inline box have none
+    pub fn horizontal_edges(&self, containing_width: f32) -> EdgeSizes {
+        let margin = self.calculate_edge_sizes("margin", "", containing_width, 0.0);
+        let border = self.calculate_edge_sizes("border", "-width", containing_width, 0.0);
+        let padding = self.calculate_edge_sizes("padding", "", containing_width, 0.0);
         EdgeSizes {
-            top: self.get_float_value(&format!("{}-top", prefix), all), // Spesifik yoksa genel
-            bottom: self.get_float_value(&format!("{}-bottom", prefix), all), // Spesifik yoksa genel
-            left: self.get_float_value(&format!("{}-left", prefix), all), // Spesifik yoksa genel
-            right: self.get_float_value(&format!("{}-right", prefix), all), // Spesifik yoksa genel
+            left: if self.continuation.before { 0.0 } else { margin.left + border.left + padding.left },
+            right: if self.continuation.after { 0.0 } else { margin.right + border.right + padding.right },
+            ..Default::default()
+        }
+    }
+
+    /// Moves the box and all of its descendants
+    pub fn translate(&mut self, dx: f32, dy: f32) {
+        self.dimensions.content.x += dx;
+        self.dimensions.content.y += dy;
+        if let LayoutBoxType::LineBox(baseline) = &mut self.box_type {
+            *baseline += dy;
+        }
+        for child in &mut self.children {
+            child.translate(dx, dy);
         }
     }
 
-    fn layout_block(&mut self, containing_block: Dimensions) {
+    /// Returns the baseline of the last line box in the box's normal flow, if there is one
+    pub fn last_baseline(&self) -> Option<f32> {
+        self.children.iter().rev().find_map(|child| match child.box_type {
+            LayoutBoxType::LineBox(baseline) => Some(baseline),
+            LayoutBoxType::BlockNode | LayoutBoxType::AnonymousBlock => child.last_baseline(),
+            _ => None,
+        })
+    }
+
+    /// Whether the box establishes a new block formatting context, whose margins never collapse
+    /// with those of its children
+    pub fn establishes_formatting_context(&self) -> bool {
+        matches!(self.box_type, LayoutBoxType::InlineBlockNode)
+            || self.styled_node.is_some_and(|styled_node| styled_node.display() == Display::FlowRoot)
+    }
+
+    // Kenar boşluğu, kenarlık ve dolguyu kapsayan bloğun genişliğine göre hesaplar
+    fn calculate_edges(&mut self, containing_width: f32) {
+        self.dimensions.margin = self.calculate_edge_sizes("margin", "", containing_width, 0.0);
+        self.dimensions.padding = self.calculate_edge_sizes("padding", "", containing_width, 0.0);
+        self.dimensions.border = self.calculate_edge_sizes("border", "-width", containing_width, 0.0);
+    }
+
+    // İçerik kutusunu kapsayan bloğun sol üst köşesine, kenarlarının içine yerleştirir
+    fn place_in(&mut self, containing_block: &Rect) {
+        self.dimensions.content.x = containing_block.x + self.dimensions.margin.left + self.dimensions.border.left + self.dimensions.padding.left;
+        self.dimensions.content.y = containing_block.y + self.dimensions.margin.top + self.dimensions.border.top + self.dimensions.padding.top;
+    }
+
+    /// Lays out a block-level box in normal flow below the content already placed in `flow`.
+    /// Its top margin collapses with the pending margins, and with its first child's unless a
+    /// border, padding or a new formatting context separates them; the same goes for the bottom
+    /// margin and the last child. `flow` is left below the box with its bottom margin pending.
+    /// `containing_height` is the containing block's height when it doesn't depend on content.
+    fn layout_block(&mut self, containing_block: &Rect, containing_height: Option<f32>, flow: &mut MarginFlow, establishes_context: bool, fonts: &FontDatabase) {
         // ÖNCE margin, padding, border'ı hesapla
-        self.dimensions.margin = self.calculate_edge_sizes("margin", 0.0);
-        self.dimensions.padding = self.calculate_edge_sizes("padding", 0.0);
-        self.dimensions.border = self.calculate_edge_sizes("border", 0.0);
-
-        // SONRA genişliği hesapla (artık padding, margin, border biliniyor)
-        let content_width = match self.get_property("width") {
-            Some(Value::Length(w, _)) => *w,
-            _ => {
-                if let Some(styled_node) = self.styled_node {
-                    if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-                        let font_size = self.get_float_value("font-size", 16.0);
-                        font_size * 0.6 * styled_node.node.to_string().len() as f32 // Metin genişliği tahmini için
-                    } else {
-                        containing_block.content.width - self.dimensions.margin.left - self.dimensions.margin.right - self.dimensions.border.left - self.dimensions.border.right - self.dimensions.padding.left - self.dimensions.padding.right
-                    }
+        self.calculate_edges(containing_block.width);
+
+        // SONRA genişliği ve auto kenar boşluklarını hesapla (artık padding, margin, border biliniyor);
+        // min-width ve max-width aşılırsa hesap sınır değeriyle tekrarlanır (CSS 2.1 §10.4)
+        let width = self.get_property("width").and_then(|value| resolve_length(value, containing_block.width));
+        self.calculate_block_width(containing_block.width, width);
+        let (min_width, max_width) = self.width_limits(containing_block.width);
+        if max_width.is_some_and(|max_width| self.dimensions.content.width > max_width) {
+            self.calculate_block_width(containing_block.width, max_width);
+        }
+        if self.dimensions.content.width < min_width {
+            self.calculate_block_width(containing_block.width, Some(min_width));
+        }
+        self.place_in(containing_block);
+
+        // Açık yükseklik içerikten bağımsızdır; min-height ve max-height her iki durumda da uygulanır (CSS 2.1 §10.7)
+        let height = self.get_property("height").and_then(|value| resolve_height(value, containing_height));
+        let (min_height, max_height) = self.height_limits(containing_height);
+        let clamp_height = |height: f32| clamp(height, min_height, max_height);
+
+        let Dimensions { margin, border, padding, .. } = self.dimensions.clone();
+        let separated_top = establishes_context || border.top > 0.0 || padding.top > 0.0;
+        // Son çocuğun alt kenar boşluğu yalnızca yüksekliği auto olan kabınkiyle birleşir
+        let separated_bottom = establishes_context || border.bottom > 0.0 || padding.bottom > 0.0 || height.is_some();
+
+        flow.margin.adjoin(margin.top);
+        let mut inner = if separated_top {
+            MarginFlow::new(flow.resolve() + border.top + padding.top)
+        } else {
+            // Üst kenar boşluğu ilk çocuğunkiyle birleşir; çocukların içeriği bekleyen kenar boşluklarını çözer
+            MarginFlow { y: flow.y, margin: flow.margin, top: None }
+        };
+        self.layout_block_children(&mut inner, height.map(clamp_height), fonts);
+        // Kenar boşlukları yalnızca yüksekliği ve min-height'ı sıfır olan boş kutunun içinden geçerek birleşir
+        let collapses_through = inner.top.is_none() && border.bottom == 0.0 && padding.bottom == 0.0
+            && height.unwrap_or(0.0) == 0.0 && min_height == 0.0;
+        if !collapses_through && (separated_bottom || inner.top.is_none()) {
+            // Son çocuğun alt kenar boşluğu kabın içinde kalır
+            inner.resolve();
+        }
+
+        match inner.top {
+            Some(top) => {
+                // Çocukların bekleyen kenar boşluklarını çözdüğü yer, kabın da kenarlık kutusunun üstüdür
+                flow.resolve();
+                let content_height = inner.y - top;
+                let used_height = clamp_height(height.unwrap_or(content_height));
+                self.dimensions.content.y = top;
+                self.dimensions.content.height = used_height;
+                flow.y = top + used_height + padding.bottom + border.bottom;
+                // İçerik kutudan taşabilir; min/max yüksekliği değiştirdiyse son çocuğun kenar boşluğu dışarı çıkmaz
+                flow.margin = if used_height == content_height { inner.margin } else { CollapsedMargin::default() };
+            }
+            None => {
+                // İçeriği olmayan kutunun üst ve alt kenar boşlukları birbiriyle ve komşularınkiyle birleşir;
+                // kutu, alt kenarlığı varmış gibi konumlanır
+                self.dimensions.content.y = inner.y + inner.margin.resolve();
+                self.dimensions.content.height = 0.0;
+                flow.margin = inner.margin;
+            }
+        }
+        flow.margin.adjoin(margin.bottom);
+    }
+
+    /// Solves the horizontal box equation of a block-level box in normal flow (CSS 2.1 §10.3.3):
+    /// margin-left + border + padding + width + padding + border + margin-right equals the
+    /// containing block's width. `None` stands for `width: auto`. Auto margins share the free
+    /// space, so a box with a fixed width and `margin: 0 auto` is centered. The horizontal margins
+    /// are recomputed from their specified values, so the equation can be solved again with a
+    /// `min-width` or `max-width` limit.
+    fn calculate_block_width(&mut self, containing_width: f32, width: Option<f32>) {
+        let is_auto = |name: &str| matches!(self.get_property(name), Some(Value::Keyword(keyword)) if keyword == "auto");
+        let (mut margin_left_auto, mut margin_right_auto) = (is_auto("margin-left"), is_auto("margin-right"));
+        // Hesap min-width ve max-width ile tekrarlanabilir; yatay kenar boşlukları her seferinde
+        // belirtilen değerlerden başlar (auto kenar boşlukları 0)
+        let margin = self.calculate_edge_sizes("margin", "", containing_width, 0.0);
+        let d = &mut self.dimensions;
+        (d.margin.left, d.margin.right) = (margin.left, margin.right);
+        let edges = d.border.left + d.padding.left + d.padding.right + d.border.right;
+
+        match width {
+            Some(width) => {
+                // Kutu zaten taşıyorsa auto kenar boşlukları 0 olur
+                if d.margin.left + edges + width + d.margin.right > containing_width {
+                    margin_left_auto = false;
+                    margin_right_auto = false;
+                }
+                d.content.width = width;
+            }
+            None => {
+                // Genişlik auto ise diğer auto değerler 0 olur ve genişlik kalan alanı alır; genişlik negatif olamaz
+                margin_left_auto = false;
+                margin_right_auto = false;
+                d.content.width = (containing_width - d.margin.left - edges - d.margin.right).max(0.0);
+            }
+        }
+
+        let free_space = containing_width - d.margin.left - edges - d.content.width - d.margin.right;
+        match (margin_left_auto, margin_right_auto) {
+            (true, true) => {
+                d.margin.left = free_space / 2.0;
+                d.margin.right = free_space / 2.0;
+            }
+            (true, false) => d.margin.left = free_space,
+            (false, true) => d.margin.right = free_space,
+            // Aşırı kısıtlanmış denklemde yazı yönünün sonundaki kenar boşluğu yok sayılır ve yeniden hesaplanır
+            (false, false) => {
+                let rtl = matches!(self.get_property("direction"), Some(Value::Keyword(keyword)) if keyword == "rtl");
+                let d = &mut self.dimensions;
+                if rtl {
+                    d.margin.left += free_space;
                 } else {
-                    containing_block.content.width - self.dimensions.margin.left - self.dimensions.margin.right - self.dimensions.border.left - self.dimensions.border.right - self.dimensions.padding.left - self.dimensions.padding.right
+                    d.margin.right += free_space;
                 }
             }
+        }
+    }
+
+    // min-width ve max-width değerlerini kapsayan bloğun genişliğine göre çözer; max-width: none sınırsızdır
+    fn width_limits(&self, containing_width: f32) -> (f32, Option<f32>) {
+        let min_width = self.get_property("min-width").and_then(|value| resolve_length(value, containing_width)).unwrap_or(0.0);
+        let max_width = self.get_property("max-width").and_then(|value| resolve_length(value, containing_width));
+        (min_width, max_width)
+    }
+
+    // min-height ve max-height değerlerini çözer; yüzdeler yalnızca belirli bir kapsayan blok yüksekliğine göre çözülür
+    fn height_limits(&self, containing_height: Option<f32>) -> (f32, Option<f32>) {
+        let min_height = self.get_property("min-height").and_then(|value| resolve_height(value, containing_height)).unwrap_or(0.0);
+        let max_height = self.get_property("max-height").and_then(|value| resolve_height(value, containing_height));
+        (min_height, max_height)
+    }
+
+    /// Lays out an inline-block at the origin of a containing block of the given width; the line
+    /// it sits on moves it into place afterwards. An `auto` width shrinks to fit the content
+    /// within `available_width` (CSS 2.1 §10.3.9).
+    pub fn layout_inline_block(&mut self, containing_width: f32, available_width: f32, fonts: &FontDatabase) {
+        self.size_inline_block(containing_width, available_width, fonts);
+        self.layout_inline_block_contents(fonts);
+    }
+
+    /// Resolves the edges and width of an inline-block without laying out its content; measuring
+    /// line widths only needs this.
+    pub fn size_inline_block(&mut self, containing_width: f32, available_width: f32, fonts: &FontDatabase) {
+        self.calculate_edges(containing_width);
+        let edges = self.horizontal_edges(containing_width);
+        self.dimensions.content.width = match self.get_property("width").and_then(|value| resolve_length(value, containing_width)) {
+            Some(width) => width,
+            None => {
+                let (min_content, max_content) = self.intrinsic_widths(fonts);
+                (available_width - edges.left - edges.right).max(min_content).min(max_content)
+            }
         };
-        self.dimensions.content.width = content_width;
+        let (min_width, max_width) = self.width_limits(containing_width);
+        self.dimensions.content.width = clamp(self.dimensions.content.width, min_width, max_width);
+        self.place_in(&Rect::default());
+    }
 
-        self.dimensions.content.x = containing_block.content.x + self.dimensions.margin.left + self.dimensions.border.left + self.dimensions.padding.left;
-        self.dimensions.content.y = containing_block.content.y + self.dimensions.margin.top + self.dimensions.border.top + self.dimensions.padding.top;
+    /// Lays out the content of an inline-block sized by `size_inline_block` and resolves its height.
+    pub fn layout_inline_block_contents(&mut self, fonts: &FontDatabase) {
+        // Satır içi blok yeni bir biçimlendirme bağlamıdır: çocukların kenar boşlukları içinde kalır
+        // Satırın yüksekliği içeriğe bağlı olduğundan yüzde yükseklikler auto gibi davranır
+        let height = self.get_property("height").and_then(|value| resolve_height(value, None));
+        let (min_height, max_height) = self.height_limits(None);
+        let clamp_height = |height: f32| clamp(height, min_height, max_height);
+        let mut flow = MarginFlow::new(self.dimensions.content.y);
+        self.layout_block_children(&mut flow, height.map(clamp_height), fonts);
+        let content_height = flow.resolve() - self.dimensions.content.y;
+        self.dimensions.content.height = clamp_height(height.unwrap_or(content_height));
+    }
+
+    /// Returns the min-content and max-content widths of the box's content (CSS Sizing §5).
+    /// Percentages can't be resolved before layout and count as zero. The widths are computed
+    /// once per box, so nested shrink-to-fit boxes are measured in linear time.
+    pub fn intrinsic_widths(&self, fonts: &FontDatabase) -> (f32, f32) {
+        if let Some(Value::Length(width, unit)) = self.get_property("width")
+            && *unit != Unit::Percent {
+            return (*width, *width);
+        }
+        *self.content_widths.get_or_init(|| self.measure_content_widths(fonts))
+    }
 
-        if let Some(styled_node) = self.styled_node {
-            if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-                let font_size = self.get_float_value("font-size", 16.0);
-                self.dimensions.content.height = font_size * 1.2; // Sabit satır yüksekliği
+    fn measure_content_widths(&self, fonts: &FontDatabase) -> (f32, f32) {
+        let mut widths: (f32, f32) = (0.0, 0.0);
+        let mut include = |(min_content, max_content): (f32, f32)| {
+            widths = (widths.0.max(min_content), widths.1.max(max_content));
+        };
+        let mut inline_children = vec![];
+        for child in &self.children {
+            match child.box_type {
+                LayoutBoxType::BlockNode => {
+                    let (min_content, max_content) = child.intrinsic_widths(fonts);
+                    let edges = child.horizontal_edges(0.0);
+                    include((min_content + edges.left + edges.right, max_content + edges.left + edges.right));
+                }
+                LayoutBoxType::AnonymousBlock => include(inline_content_widths(&child.children.iter().collect::<Vec<_>>(), fonts)),
+                _ => inline_children.push(child),
             }
         }
+        if !inline_children.is_empty() {
+            include(inline_content_widths(&inline_children, fonts));
+        }
+        widths
+    }
 
-        let mut current_y = self.dimensions.content.y;
+    // Çocukları içerik kutusunun içine, `flow`daki içeriğin altına yerleştirir; yüksekliği çağıran belirler
+    fn layout_block_children(&mut self, flow: &mut MarginFlow, definite_height: Option<f32>, fonts: &FontDatabase) {
         let mut line_children = Vec::new();
         let mut new_children = Vec::new(); // Yeni çocukları toplamak için geçici vektör
 
         for child in self.children.drain(..) {
             match child.box_type {
                 LayoutBoxType::BlockNode => {
-                    // Önceki satır içi çocukları bir satır kutusuna yerleştir ve düzenle
-                    if !line_children.is_empty() {
-                        let mut line_box = LayoutBox::new(LayoutBoxType::LineBox);
-                        line_box.children.append(&mut line_children);
-                        line_box.dimensions.content.y = current_y;
-
-                        let line_containing_block = Dimensions {
-                            content: Rect {
-                                x: self.dimensions.content.x,
-                                y: current_y,
-                                width: self.dimensions.content.width,
-                                height: 0.0, // `LineBox`'ın kendi içeriğine göre yüksekliğini belirlemesine izin ver
-                            },
-                            ..Default::default()
-                        };
-                        line_box.layout(line_containing_block);
-
-                        // LineBox için font-size'a dayalı doğru yükseklik hesapla
-                        let line_box_height = if !line_box.children.is_empty() {
-                            let mut max_line_height: f32 = 0.0;
-                            for child in &line_box.children {
-                                let child_line_height = if let Some(styled_node) = child.styled_node {
-                                    if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-                                        // Metin düğümü için font-size'a dayalı line-height hesapla
-                                        let font_size = child.get_float_value("font-size", 16.0);
-                                        font_size * 1.2 // Sabit line-height çarpanı
-                                    } else {
-                                        // Inline element için kendi hesaplanmış yüksekliğini kullan
-                                        child.dimensions.margin_box().height
-                                    }
-                                } else {
-                                    child.dimensions.margin_box().height
-                                };
-                                max_line_height = max_line_height.max(child_line_height);
-                            }
-                            max_line_height
-                        } else {
-                            line_box.dimensions.margin_box().height
-                        };
-
-                        current_y += line_box_height;
-                        new_children.push(line_box); // Oluşturulan satır kutusunu yeni vektöre ekle
-                    }
+                    // Önceki satır içi çocukları satır kutularına yerleştir
+                    place_lines(&mut line_children, self.styled_node, &self.dimensions.content, flow, new_children.is_empty(), fonts, &mut new_children);
 
                     // Blok çocuğu düzenle
                     let mut block_child = child;
-                    block_child.dimensions.content.y = current_y;
-                    let child_containing_block = Dimensions {
-                        content: Rect {
-                            x: self.dimensions.content.x,
-                            y: current_y,
-                            width: self.dimensions.content.width,
-                            height: 0.0,
-                        },
-                        ..Default::default()
-                    };
-                    block_child.layout(child_containing_block);
-                    current_y += block_child.dimensions.margin_box().height;
+                    let establishes_context = block_child.establishes_formatting_context();
+                    block_child.layout_block(&self.dimensions.content, definite_height, flow, establishes_context, fonts);
                     new_children.push(block_child); // Blok çocuğunu yeni vektöre ekle
                 }
-                LayoutBoxType::InlineNode | LayoutBoxType::AnonymousBlock => {
-                    line_children.push(child); // Satır içi çocukları topla
+                LayoutBoxType::AnonymousBlock => {
+                    place_lines(&mut line_children, self.styled_node, &self.dimensions.content, flow, new_children.is_empty(), fonts, &mut new_children);
+
+                    // Anonim blokların kendi stili yoktur: kabın tüm genişliğini kaplar, satırları kabın özellikleriyle kurulur.
+                    // text-indent yalnızca kabın ilk çocuğu olan anonim bloğa uygulanır.
+                    let mut anonymous = child;
+                    let mut line_boxes = vec![];
+                    let top = flow.y + flow.margin.resolve();
+                    let height = place_lines(&mut anonymous.children, self.styled_node, &self.dimensions.content, flow, new_children.is_empty(), fonts, &mut line_boxes);
+                    anonymous.children = line_boxes;
+                    anonymous.dimensions.content = Rect { x: self.dimensions.content.x, y: top, width: self.dimensions.content.width, height };
+                    new_children.push(anonymous);
                 }
-                _ => {} // Diğer LayoutBoxType'ları şimdilik yoksay
+                _ => line_children.push(child), // Satır içi çocukları topla
             }
         }
 
-        // Kalan satır içi çocukları bir satır kutusuna yerleştir ve düzenle
-        if !line_children.is_empty() {
-            let mut line_box = LayoutBox::new(LayoutBoxType::LineBox);
-            line_box.children.append(&mut line_children);
-            line_box.dimensions.content.y = current_y;
-
-            let line_containing_block = Dimensions {
-                content: Rect {
-                    x: self.dimensions.content.x,
-                    y: current_y,
-                    width: self.dimensions.content.width,
-                    height: 0.0, // `LineBox`'ın kendi içeriğine göre yüksekliğini belirlemesine izin ver
-                },
-                ..Default::default()
-            };
-            line_box.layout(line_containing_block);
-
-            // LineBox için font-size'a dayalı doğru yükseklik hesapla
-            let line_box_height = if !line_box.children.is_empty() {
-                let mut max_line_height: f32 = 0.0;
-                for child in &line_box.children {
-                    let child_line_height = if let Some(styled_node) = child.styled_node {
-                        if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-                            // Metin düğümü için font-size'a dayalı line-height hesapla
-                            let font_size = styled_node.computed_values.get_key_value("font-size").unwrap_or((&"font-size".to_string(), &Value::Length(16.0, Unit::Px))).1;
-                            let r = match font_size {
-                                Value::Length(f, _) => f * 1.2,
-                                _ => 0.0
-                            };
-                            r
-                        } else {
-                            // Inline element için kendi hesaplanmış yüksekliğini kullan
-                            child.dimensions.margin_box().height
-                        }
-                    } else {
-                        child.dimensions.margin_box().height
-                    };
-                    max_line_height = max_line_height.max(child_line_height);
-                }
-                max_line_height
-            } else {
-                line_box.dimensions.margin_box().height
-            };
-
-            current_y += line_box_height;
-            new_children.push(line_box); // Oluşturulan satır kutusunu yeni vektöre ekle
-        }
+        // Kalan satır içi çocukları satır kutularına yerleştir
+        place_lines(&mut line_children, self.styled_node, &self.dimensions.content, flow, new_children.is_empty(), fonts, &mut new_children);
 
         self.children = new_children; // self.children'ı yeni vektörle değiştir
+    }
+}
 
-        if let Some(styled_node) = self.styled_node {
-            if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-            } else {
-                let children_total_height = current_y - self.dimensions.content.y;
-                self.dimensions.content.height = children_total_height;
-            }
-        } else {
-            let children_total_height = current_y - self.dimensions.content.y;
-            self.dimensions.content.height = children_total_height;
-        }
+// Toplanan satır içi çocukları kapsayan bloğun genişliğine sığacak kadar satır kutusuna dağıtır ve satırların
+// toplam yüksekliğini döndürür. text-indent yalnızca kabın ilk satırlarına (`first_line`) uygulanır.
+// Yüksekliği olan satırlar bekleyen kenar boşluklarını ayırır; boş satırlar kenar boşluklarının birleşmesini engellemez.
+fn place_lines<'a>(line_children: &mut Vec<LayoutBox<'a>>, container_node: Option<&StyledNode>, container: &Rect, flow: &mut MarginFlow, first_line: bool, fonts: &FontDatabase, new_children: &mut Vec<LayoutBox<'a>>) -> f32 {
+    if line_children.is_empty() {
+        return 0.0;
     }
+    let y = flow.y + flow.margin.resolve();
+    let line_boxes = layout_lines(std::mem::take(line_children), container_node, container, y, first_line, fonts);
+    let height: f32 = line_boxes.iter().map(|line_box| line_box.dimensions.content.height).sum();
+    new_children.extend(line_boxes);
+    if height > 0.0 {
+        flow.y = flow.resolve() + height;
+    }
+    height
+}
 
-    // Satır içi düzenlemeyi yapar
-    fn layout_inline(&mut self, containing_block: Dimensions) {
-        self.dimensions.margin = self.calculate_edge_sizes("margin", 0.0);
-        self.dimensions.padding = self.calculate_edge_sizes("padding", 0.0);
-        self.dimensions.border = self.calculate_edge_sizes("border", 0.0);
-
-        self.dimensions.content.x = containing_block.content.x + self.dimensions.margin.left + self.dimensions.border.left + self.dimensions.padding.left;
-        self.dimensions.content.y = containing_block.content.y + self.dimensions.margin.top + self.dimensions.border.top + self.dimensions.padding.top;
-
-        let content_width = match self.get_property("width") {
-            Some(Value::Length(w, _)) => *w,
-            _ => {
-                if let Some(styled_node) = self.styled_node {
-                    if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-                        let font_size = self.get_float_value("font-size", 16.0);
-                        font_size * 0.6 * styled_node.node.to_string().len() as f32 // Metin genişliği tahmini için
-                    } else {
-                        0.0
-                    }
-                } else {
-                    0.0
-                }
-            }
-        };
-        self.dimensions.content.width = content_width;
-
-        let content_height = match self.get_property("height") {
-            Some(Value::Length(h, _)) => *h,
-            _ => {
-                if let Some(styled_node) = self.styled_node {
-                    if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-                        let font_size = self.get_float_value("font-size", 16.0);
-                        font_size * 1.2 // Sabit satır yüksekliği
-                    } else {
-                        self.get_float_value("font-size", 16.0) * 1.2
-                    }
-                } else {
-                    self.get_float_value("font-size", 16.0) * 1.2
-                }
-            }
-        };
-        self.dimensions.content.height = content_height;
+// Değeri önce üst, sonra alt sınıra kısar; çakışmada min kazanır (CSS 2.1 §10.4, §10.7)
+fn clamp(value: f32, min: f32, max: Option<f32>) -> f32 {
+    max.map_or(value, |max| value.min(max)).max(min)
+}
 
-        // Inline elemanların çocuklarını da düzenle
-        let mut current_child_x = self.dimensions.content.x;
-        let mut max_child_height: f32 = 0.0;
+// Yükseklik değerini piksele çevirir; yüzdeler yalnızca kapsayan bloğun yüksekliği içerikten bağımsızsa
+// çözülür, aksi halde değer auto (min-height için 0, max-height için none) sayılır (CSS 2.1 §10.5)
+fn resolve_height(value: &Value, containing_height: Option<f32>) -> Option<f32> {
+    match containing_height {
+        Some(containing_height) => resolve_length(value, containing_height),
+        None => match value {
+            Value::Length(_, Unit::Percent) => None,
+            Value::Length(f, _) => Some(*f),
+            Value::Math(expr) => expr.evaluate(&|f, unit| (unit != Unit::Percent).then_some(f)),
+            _ => None,
+        },
+    }
+}
 
-        for child in &mut self.children {
-            let child_containing_block = Dimensions {
-                content: Rect {
-                    x: current_child_x,
-                    y: self.dimensions.content.y,
-                    width: self.dimensions.content.width - (current_child_x - self.dimensions.content.x),
-                    height: self.dimensions.content.height,
-                },
-                ..Default::default()
-            };
-            child.layout(child_containing_block);
-            current_child_x += child.dimensions.margin_box().width;
-            max_child_height = max_child_height.max(child.dimensions.margin_box().height);
+/// Converts a computed length to pixels; percentages are resolved against `reference`
+pub fn resolve_length(value: &Value, reference: f32) -> Option<f32> {
+    match value {
+        Value::Length(f, Unit::Percent) => Some(f * reference / 100.0),
+        Value::Length(f, _) => Some(*f),
+        // Yüzdeler içeren matematik ifadeleri düzen aşamasında çözülür
+        Value::Math(expr) => expr.evaluate(&|f, unit| match unit {
+            Unit::Percent => Some(f * reference / 100.0),
+            _ => Some(f),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use crate::css::media::MediaEnvironment;
+    use crate::css::parser::Parser as CssParser;
+    use crate::html::enums::{AttrName, NodeType};
+    use crate::html::parser::Parser as HtmlParser;
+    use crate::layout::tree::build_layout_tree;
+    use crate::style::structs::style_tree;
+
+    // Belgeyi 800x600 görüntü alanında yerleştirir ve `id` özniteliği olan kutuların boyutlarını döndürür
+    fn layout(html: &str, css: &str) -> HashMap<String, Dimensions> {
+        let dom = HtmlParser::new(html.to_string()).parse();
+        let stylesheets = vec![CssParser::new(css.to_string()).parse()];
+        let mut viewport = Dimensions::default();
+        viewport.content.width = 800.0;
+        viewport.content.height = 600.0;
+        let root = style_tree(&dom, &stylesheets, &viewport, &MediaEnvironment::default());
+        let mut layout_root = build_layout_tree(&root);
+        layout_root.layout(viewport, &FontDatabase::default());
+
+        fn collect(layout_box: &LayoutBox, boxes: &mut HashMap<String, Dimensions>) {
+            if let Some(styled_node) = layout_box.styled_node
+                && let NodeType::Element(element) = &styled_node.node.node_type
+                && let Some(id) = element.attributes.get(&AttrName::Id) {
+                boxes.insert(id.clone(), layout_box.dimensions.clone());
+            }
+            layout_box.children.iter().for_each(|child| collect(child, boxes));
         }
-        
-        // Çocukların toplam genişliğini ve en yüksek yüksekliğini ayarla
-        if !self.children.is_empty() {
-            self.dimensions.content.width = current_child_x - self.dimensions.content.x;
-            self.dimensions.content.height = max_child_height;
+        let mut boxes = HashMap::new();
+        collect(&layout_root, &mut boxes);
+        boxes
+    }
+
+    #[test]
+    fn collapsed_margin_is_largest_positive_plus_most_negative() {
+        let mut margin = CollapsedMargin::default();
+        for value in [10.0, 30.0, -5.0, -20.0, 0.0] {
+            margin.adjoin(value);
         }
+        assert_eq!(margin.resolve(), 10.0);
+    }
+
+    #[test]
+    fn sibling_margins_collapse() {
+        let boxes = layout(
+            r#"<div><div id="a"></div><div id="b"></div></div>"#,
+            "#a { height: 10px; margin-bottom: 20px } #b { height: 10px; margin-top: 30px }",
+        );
+        assert_eq!(boxes["a"].content.y, 0.0);
+        assert_eq!(boxes["b"].content.y, 40.0);
     }
 
-    // Satır kutularını düzenler (Inline Formatting Context)
-    fn layout_line_box(&mut self, containing_block: Dimensions) {
-        self.dimensions.content.x = containing_block.content.x;
-        self.dimensions.content.y = containing_block.content.y;
-        self.dimensions.content.width = containing_block.content.width;
+    #[test]
+    fn parent_collapses_with_first_and_last_child() {
+        let boxes = layout(
+            r#"<div><div id="p"><div id="c"></div></div><div id="s"></div></div>"#,
+            "#p { margin-top: 10px; margin-bottom: 15px } #c { height: 10px; margin-top: 30px; margin-bottom: 40px } #s { height: 10px }",
+        );
+        // İlk çocuğun üst kenar boşluğu kabınkiyle birleşip kabın dışına çıkar
+        assert_eq!(boxes["p"].content.y, 30.0);
+        assert_eq!(boxes["c"].content.y, 30.0);
+        // Son çocuğun alt kenar boşluğu da kabın altından dışarı çıkar
+        assert_eq!(boxes["p"].content.height, 10.0);
+        assert_eq!(boxes["s"].content.y, 80.0);
+    }
 
-        let mut current_x = self.dimensions.content.x;
-        let mut max_height: f32 = 0.0;
+    #[test]
+    fn border_and_padding_keep_margins_apart() {
+        let boxes = layout(
+            r#"<div><div id="p"><div id="c"></div></div><div id="s"></div></div>"#,
+            "#p { margin-top: 10px; border-top: 1px solid; padding-bottom: 5px } #c { height: 10px; margin-top: 30px; margin-bottom: 40px } #s { height: 10px }",
+        );
+        assert_eq!(boxes["p"].content.y, 11.0);
+        assert_eq!(boxes["c"].content.y, 41.0);
+        // Alt dolgu son çocuğun kenar boşluğunu kabın içinde tutar
+        assert_eq!(boxes["p"].content.height, 80.0);
+        assert_eq!(boxes["s"].content.y, 96.0);
+    }
 
-        for child in &mut self.children {
-            // Çocuğun kapsayan bloğunu, satır kutusunun mevcut konumuyla oluştur
-
-            let child_containing_block = Dimensions {
-                content: Rect {
-                    x: current_x,
-                    y: self.dimensions.content.y,
-                    width: containing_block.content.width - (current_x - self.dimensions.content.x),
-                    height: containing_block.content.height // Çocuk elementlerin ana kapsayan bloğa göre boyutlanmasına izin ver
-                },
-                ..Default::default()
-            };
-            child.layout(child_containing_block);
-
-            // Çocuğun boyutlarını kullanarak satır kutusunun genişliğini ve yüksekliğini güncelle
-            current_x += child.dimensions.margin_box().width;
-            
-            // Çocuk için font-size'a dayalı doğru line-height hesapla
-            let child_line_height = if let Some(styled_node) = child.styled_node {
-                if let crate::html::enums::NodeType::Text(_) = styled_node.node.node_type {
-                    // Metin düğümü için font-size'a dayalı line-height hesapla
-                    let l = styled_node.computed_values.get_key_value("font-size").unwrap_or((&"font-size".to_string(), &Value::Length(16.0, Unit::Px))).1;
-                    let r = match l {
-                        Value::Length(f, _) => f * 1.2,
-                        _ => 0.0
-                    };
-                    r
-                } else {
-                    // Inline element için kendi hesaplanmış yüksekliğini kullan
-                    child.dimensions.margin_box().height
-                }
-            } else {
-                child.dimensions.margin_box().height
-            };
-            max_height = max_height.max(child_line_height);
-        }
-        self.dimensions.content.width = current_x - self.dimensions.content.x;
-        self.dimensions.content.height = max_height;
+    #[test]
+    fn new_formatting_context_keeps_margins_apart() {
+        let boxes = layout(
+            r#"<div><div id="p"><div id="c"></div></div></div>"#,
+            "#p { display: flow-root; margin-top: 10px } #c { height: 10px; margin-top: 30px; margin-bottom: 5px }",
+        );
+        assert_eq!(boxes["p"].content.y, 10.0);
+        assert_eq!(boxes["c"].content.y, 40.0);
+        assert_eq!(boxes["p"].content.height, 45.0);
+    }
+
+    #[test]
+    fn margins_collapse_through_empty_blocks() {
+        let boxes = layout(
+            r#"<div><div id="a"></div><div id="e"></div><div id="b"></div></div>"#,
+            "#a { height: 10px; margin-bottom: 10px } #e { margin-top: 20px; margin-bottom: 30px } #b { height: 10px; margin-top: 5px }",
+        );
+        assert_eq!(boxes["e"].content.height, 0.0);
+        assert_eq!(boxes["b"].content.y, 40.0);
+
+        // Yüksekliği ya da min-height'ı olan boş kutunun içinden geçilmez
+        let boxes = layout(
+            r#"<div><div id="a"></div><div id="e"></div><div id="b"></div></div>"#,
+            "#a { height: 10px; margin-bottom: 10px } #e { min-height: 1px; margin-top: 20px; margin-bottom: 30px } #b { height: 10px; margin-top: 5px }",
+        );
+        assert_eq!(boxes["e"].content.y, 30.0);
+        assert_eq!(boxes["b"].content.y, 61.0);
+    }
+
+    #[test]
+    fn mixed_positive_and_negative_margins() {
+        let boxes = layout(
+            r#"<div><div id="a"></div><div id="b"></div></div>"#,
+            "#a { height: 10px; margin-bottom: 30px } #b { height: 10px; margin-top: -10px }",
+        );
+        assert_eq!(boxes["b"].content.y, 30.0);
+
+        // Boş kutunun içinden geçen kenar boşlukları: en büyük pozitif (30) ile en negatifin (-25) toplamı
+        let boxes = layout(
+            r#"<div><div id="a"></div><div id="e"></div><div id="b"></div></div>"#,
+            "#a { height: 10px; margin-bottom: 30px } #e { margin-top: 20px; margin-bottom: -25px } #b { height: 10px; margin-top: -5px }",
+        );
+        assert_eq!(boxes["b"].content.y, 15.0);
+
+        let boxes = layout(
+            r#"<div><div id="a"></div><div id="b"></div></div>"#,
+            "#a { height: 10px; margin-bottom: -10px } #b { height: 10px; margin-top: -20px }",
+        );
+        assert_eq!(boxes["b"].content.y, -10.0);
+    }
+
+    #[test]
+    fn auto_width_fills_the_containing_block() {
+        let boxes = layout(
+            r#"<div><div id="a"></div></div>"#,
+            "#a { margin-left: 10px; margin-right: 20px; padding: 0 5px; border-left: 2px solid }",
+        );
+        assert_eq!(boxes["a"].content.width, 800.0 - 10.0 - 20.0 - 10.0 - 2.0);
+        // Genişlik auto iken auto kenar boşlukları 0 olur
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { margin: 0 auto }");
+        assert_eq!(boxes["a"].content.width, 800.0);
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (0.0, 0.0));
+    }
+
+    #[test]
+    fn auto_margins_take_the_free_space() {
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 200px; margin-left: auto; margin-right: 100px }");
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (500.0, 100.0));
+        assert_eq!(boxes["a"].content.x, 500.0);
+
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 200px; margin: 0 auto; padding: 0 10px }");
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (290.0, 290.0));
+        // Anahtar kelimeler büyük/küçük harfe duyarsızdır
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 200px; margin-left: AUTO; margin-right: Auto }");
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (300.0, 300.0));
+    }
+
+    #[test]
+    fn over_constrained_width_adjusts_the_end_margin() {
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 900px; margin: 0 auto }");
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (0.0, -100.0));
+
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 500px; margin-left: 50px; margin-right: 50px }");
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (50.0, 250.0));
+
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 500px; margin-left: 50px; margin-right: 50px; direction: RTL }");
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (250.0, 50.0));
+    }
+
+    #[test]
+    fn width_limits_re_solve_auto_margins() {
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 500px; max-width: 300px; margin: 0 auto }");
+        assert_eq!(boxes["a"].content.width, 300.0);
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (250.0, 250.0));
+
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 200px; min-width: 400px; margin: 0 auto }");
+        assert_eq!(boxes["a"].content.width, 400.0);
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (200.0, 200.0));
+
+        // Aşırı kısıtlanmış denklemde de sağ kenar boşluğu belirtilen değerinden yeniden hesaplanır
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 500px; max-width: 300px; margin: 0 10px }");
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (10.0, 490.0));
+    }
+
+    #[test]
+    fn percentages_resolve_against_the_containing_block() {
+        let boxes = layout(
+            r#"<div><div id="p"><div id="c"></div></div></div>"#,
+            "#p { width: 400px; height: 200px; margin-left: 100px } \
+             #c { width: 50%; height: 25%; padding-left: 10%; margin-top: 5%; border-left: 2px solid }",
+        );
+        assert_eq!(boxes["c"].content.width, 200.0);
+        assert_eq!(boxes["c"].content.height, 50.0);
+        // Dikey kenar boşlukları ve dolgular da kapsayan bloğun genişliğine göredir
+        assert_eq!(boxes["c"].padding.left, 40.0);
+        assert_eq!(boxes["c"].margin.top, 20.0);
+        assert_eq!(boxes["c"].content.x, 100.0 + 2.0 + 40.0);
+    }
+
+    #[test]
+    fn nested_inline_blocks_are_measured_once() {
+        // Her düzeyde yeniden yerleşim üstel süre alırdı; önbellekle derinlik doğrusal maliyetlidir
+        let depth = 40;
+        let html = format!("<div><div id=\"outer\">{}<span id=\"inner\">x</span>{}</div></div>", "<span>".repeat(depth), "</span>".repeat(depth));
+        let boxes = layout(&html, "span { display: inline-block; padding-left: 1px } #outer { display: inline-block }");
+        let inner = &boxes["inner"];
+        let outer = &boxes["outer"];
+        assert!(inner.content.width > 0.0);
+        assert_eq!(outer.content.width, inner.content.width + (depth + 1) as f32);
+        assert_eq!(inner.content.x, outer.content.x + (depth + 1) as f32);
+    }
+
+    #[test]
+    fn explicit_height_lets_content_overflow() {
+        let boxes = layout(
+            r#"<div><div id="p"><div id="c"></div></div><div id="n"></div></div>"#,
+            "#p { height: 50px; padding-bottom: 5px } #c { height: 200px; margin-bottom: 20px }",
+        );
+        assert_eq!(boxes["p"].content.height, 50.0);
+        assert_eq!(boxes["c"].content.height, 200.0);
+        // Taşan içerik sonraki kardeşi itmez
+        assert_eq!(boxes["n"].content.y, boxes["p"].content.y + 55.0);
+    }
+
+    #[test]
+    fn min_and_max_height_clamp_the_height() {
+        let css = |limits: &str| format!("#p {{ {} }} #c {{ height: 100px }}", limits);
+        let height = |limits: &str| layout(r#"<div><div id="p"><div id="c"></div></div></div>"#, &css(limits))["p"].content.height;
+        assert_eq!(height(""), 100.0);
+        assert_eq!(height("min-height: 150px"), 150.0);
+        assert_eq!(height("max-height: 60px"), 60.0);
+        assert_eq!(height("height: 30px; min-height: 50px"), 50.0);
+        assert_eq!(height("height: 300px; max-height: 120px"), 120.0);
+        // Çakışırlarsa min-height kazanır
+        assert_eq!(height("min-height: 80px; max-height: 40px"), 80.0);
+        assert_eq!(height("height: 10px; min-height: 80px; max-height: 40px"), 80.0);
+    }
+
+    #[test]
+    fn min_width_wins_over_max_width() {
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { width: 100px; min-width: 300px; max-width: 200px }");
+        assert_eq!(boxes["a"].content.width, 300.0);
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { min-width: 300px; max-width: 200px; margin-left: 10px }");
+        assert_eq!(boxes["a"].content.width, 300.0);
+        assert_eq!((boxes["a"].margin.left, boxes["a"].margin.right), (10.0, 490.0));
+        let boxes = layout(r#"<div><div id="a"></div></div>"#, "#a { min-width: 50%; max-width: 100px }");
+        assert_eq!(boxes["a"].content.width, 400.0);
+    }
+
+    #[test]
+    fn percentage_heights_against_an_auto_height_container_are_auto() {
+        let html = r#"<div><div id="p"><div id="c"><div id="g"></div></div></div></div>"#;
+        let boxes = layout(html, "#c { height: 50%; min-height: 10%; max-height: 5% } #g { height: 30px }");
+        assert_eq!(boxes["c"].content.height, 30.0);
+        assert_eq!(boxes["p"].content.height, 30.0);
+        // Kapsayan bloğun yüksekliği belirliyse yüzdeler ona göre çözülür; belirli yükseklik zincirleme aktarılır
+        let boxes = layout(html, "#p { height: 200px } #c { height: 50% } #g { height: 25% }");
+        assert_eq!(boxes["c"].content.height, 100.0);
+        assert_eq!(boxes["g"].content.height, 25.0);
+        let boxes = layout(html, "#p { height: 200px } #c { max-height: 10% } #g { height: 30px }");
+        assert_eq!(boxes["c"].content.height, 20.0);
+        let boxes = layout(html, "#p { min-height: 200px } #c { height: 50% } #g { height: 30px }");
+        assert_eq!(boxes["c"].content.height, 30.0);
     }
 }
@@ -0,0 +1,129 @@
+//! Box tree generation module
+//!
+//! This module builds the layout tree from the style tree following CSS 2.1 §9.2: inline runs
+//! next to block-level boxes are wrapped in anonymous block boxes, and inline boxes containing
+//! block-level boxes are split around them.
+
+use super::enums::LayoutBoxType;
+use super::structs::LayoutBox;
+use crate::css::enums::Display;
+use crate::html::enums::NodeType;
+use crate::style::structs::StyledNode;
+
+/// Builds the layout tree of the root element; the root box is always a block
+pub fn build_layout_tree<'a>(root: &'a StyledNode<'a>) -> LayoutBox<'a> {
+    // Gizli kök eleman kutu oluşturmaz; düzenin yine de boş bir kökü olur
+    if root.display() == Display::None {
+        return LayoutBox::new(LayoutBoxType::AnonymousBlock);
+    }
+    let mut root_box = LayoutBox::new(LayoutBoxType::BlockNode);
+    root_box.styled_node = Some(root);
+    root_box.children = wrap_inline_runs(generate_children(root));
+    root_box
+}
+
+// Düğümün kutusunu ve alt ağacını oluşturur
+fn generate_box<'a>(node: &'a StyledNode<'a>) -> LayoutBox<'a> {
+    let box_type = match node.node.node_type {
+        NodeType::Text(_) => LayoutBoxType::TextNode,
+        NodeType::Element(_) => match node.display() {
+            Display::Inline => LayoutBoxType::InlineNode,
+            Display::InlineBlock => LayoutBoxType::InlineBlockNode,
+            // contents ve none buraya ulaşmaz; ebeveynleri onları çocuk listesinde çözer
+            Display::Block | Display::FlowRoot | Display::Contents | Display::None => LayoutBoxType::BlockNode,
+        },
+    };
+    let mut layout_box = LayoutBox::new(box_type);
+    layout_box.styled_node = Some(node);
+    let children = generate_children(node);
+    layout_box.children = match layout_box.box_type {
+        // Satır içi kutuların blok çocukları ebeveyn tarafından bölünerek çıkarılır
+        LayoutBoxType::InlineNode => children,
+        _ => wrap_inline_runs(children),
+    };
+    layout_box
+}
+
+// Çocuk kutuları oluşturur: display: none alt ağaçları atlanır, display: contents elemanlarının yerini
+// çocukları alır, blok içeren satır içi kutular blokların çevresinden bölünür
+fn generate_children<'a>(node: &'a StyledNode<'a>) -> Vec<LayoutBox<'a>> {
+    let mut children = vec![];
+    for child in &node.children {
+        match child.display() {
+            Display::None => {}
+            Display::Contents => children.extend(generate_children(child)),
+            _ => {
+                let child_box = generate_box(child);
+                if matches!(child_box.box_type, LayoutBoxType::InlineNode) && child_box.children.iter().any(is_block_level) {
+                    children.extend(split_inline(child_box));
+                } else {
+                    children.push(child_box);
+                }
+            }
+        }
+    }
+    children
+}
+
+fn is_block_level(layout_box: &LayoutBox) -> bool {
+    matches!(layout_box.box_type, LayoutBoxType::BlockNode | LayoutBoxType::AnonymousBlock)
+}
+
+// Blok içeren satır içi kutuyu böler (CSS 2.1 §9.2.1.1): bloklardan önceki ve sonraki satır içi içerik
+// aynı elemanın ayrı kutularında kalır, bloklar ebeveyn seviyesine çıkar.
+// Kenar boşlukları, kenarlıklar ve dolgular yalnızca ilk bölümün başında ve son bölümün sonunda bulunur.
+fn split_inline<'a>(inline_box: LayoutBox<'a>) -> Vec<LayoutBox<'a>> {
+    let styled_node = inline_box.styled_node;
+    let part = |children: Vec<LayoutBox<'a>>| {
+        let mut part = LayoutBox::new(LayoutBoxType::InlineNode);
+        part.styled_node = styled_node;
+        part.children = children;
+        part
+    };
+    let mut boxes = vec![];
+    let mut inline_children = vec![];
+    for child in inline_box.children {
+        if is_block_level(&child) {
+            if !inline_children.is_empty() {
+                boxes.push(part(std::mem::take(&mut inline_children)));
+            }
+            boxes.push(child);
+        } else {
+            inline_children.push(child);
+        }
+    }
+    if !inline_children.is_empty() {
+        boxes.push(part(inline_children));
+    }
+
+    let parts: Vec<usize> = (0..boxes.len()).filter(|index| !is_block_level(&boxes[*index])).collect();
+    for (position, index) in parts.iter().enumerate() {
+        boxes[*index].continuation.before = position > 0;
+        boxes[*index].continuation.after = position + 1 < parts.len();
+    }
+    boxes
+}
+
+// Blok seviyesi çocukları olan bir blok kapsayıcıda ardışık satır içi çocukları anonim blok kutularına sarar;
+// böylece bir blok kapsayıcının çocukları ya yalnızca blok ya da yalnızca satır içi seviyededir
+fn wrap_inline_runs(children: Vec<LayoutBox>) -> Vec<LayoutBox> {
+    if !children.iter().any(is_block_level) {
+        return children;
+    }
+    let mut wrapped: Vec<LayoutBox> = vec![];
+    let mut run_open = false; // Son eklenen kutu, satır içi dizinin süren anonim bloğu mu
+    for child in children {
+        if is_block_level(&child) {
+            wrapped.push(child);
+            run_open = false;
+        } else if run_open {
+            wrapped.last_mut().unwrap().children.push(child);
+        } else {
+            let mut anonymous = LayoutBox::new(LayoutBoxType::AnonymousBlock);
+            anonymous.children.push(child);
+            wrapped.push(anonymous);
+            run_open = true;
+        }
+    }
+    wrapped
+}
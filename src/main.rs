@@ -3,15 +3,20 @@ mod css;
 mod style;
 mod layout;
 mod painting;
+mod resource;
+mod font;
 
 // html modülünden gerekli öğeleri içe aktar
 use html::parser::Parser as HtmlParser;
 use css::parser::Parser as CssParser;
-use style::structs::{style_tree, StyledNode};
-use layout::structs::{LayoutBox, Dimensions};
-use layout::enums::LayoutBoxType;
-use html::enums::NodeType; // Düzeltilen import
+use style::structs::style_tree;
+use css::media::MediaEnvironment;
+use layout::structs::Dimensions;
+use layout::tree::build_layout_tree;
 use painting::structs::{build_display_list, paint_to_image};
+use resource::structs::FileLoader;
+use font::structs::FontDatabase;
+use std::rc::Rc;
 
 fn main() {
     let html_input = "<html>
@@ -45,54 +50,36 @@ fn main() {
                                 padding: 20px;
                             }".to_string();
     let dom_tree = HtmlParser::new(html_input).parse();
-    let css_tree = CssParser::new(css_input).parse();
-
-    // Stil ağacını oluştur ve yazdır
-    let styled_tree = style_tree(&dom_tree, &css_tree);
+    // @import ile başvurulan stil sayfaları çalışma dizinine göre yüklenir
+    let mut css_parser = CssParser::with_loader(css_input, "style.css", Rc::new(FileLoader));
+    let stylesheets = vec![css_parser.parse()];
+    // Ayrıştırma sırasında atlanan kural ve bildirimleri bildir
+    for warning in &css_parser.warnings {
+        eprintln!("CSS uyarısı {}", warning);
+    }
+    // Sistem fontlarını ve @font-face ile tanımlanan fontları yükle
+    let mut fonts = FontDatabase::default();
+    fonts.load_system_fonts();
+    for warning in fonts.load_font_faces(&stylesheets, &FileLoader) {
+        eprintln!("Font uyarısı: {}", warning);
+    }
 
-    // Düzen ağacını oluştur
-    let mut layout_tree = build_layout_tree(&styled_tree);
-    // Düzen hesaplamasını başlat
     let initial_containing_block = Dimensions {
         content: crate::layout::structs::Rect { x: 0.0, y: 0.0, width: 800.0, height: 600.0 }, // Varsayılan pencere boyutları
         ..Default::default()
     };
-    layout_tree.layout(initial_containing_block.clone());
+
+    // Stil ağacını oluştur; göreli birimler görüntü alanına göre çözülür
+    let styled_tree = style_tree(&dom_tree, &stylesheets, &initial_containing_block, &MediaEnvironment::default());
+
+    // Düzen ağacını oluştur
+    let mut layout_tree = build_layout_tree(&styled_tree);
+    // Düzen hesaplamasını başlat
+    layout_tree.layout(initial_containing_block.clone(), &fonts);
     let display_list = build_display_list(&layout_tree);
     // println!("{:#?}", display_list);
 
     let width = initial_containing_block.content.width as u32;
     let height = initial_containing_block.content.height as u32;
-    paint_to_image(&display_list, width, height, "output.png");
-}
-
-// Stil ağacından düzen ağacını oluşturan yardımcı fonksiyon
-fn build_layout_tree<'a>(styled_node: &'a StyledNode<'a>) -> LayoutBox<'a> {
-    let display = styled_node.get_property("display")
-                             .and_then(|v| {
-                                 if let crate::css::enums::Value::Display(d) = v {
-                                     Some(d.clone()) // Display değerini klonla
-                                 } else {
-                                     None
-                                 }
-                             })
-                             .unwrap_or_else(|| crate::css::enums::Display::Block); // Varsayılan olarak Block
-
-    let mut layout_box = LayoutBox::new(match styled_node.node.node_type { // Bu satırda styled_node.node.node_type kullanıldığı için NodeType import'u gerekli
-        NodeType::Element(_) => { // `ref data` yerine `_` kullanıldı
-            match display {
-                crate::css::enums::Display::Block => LayoutBoxType::BlockNode,
-                crate::css::enums::Display::Inline => LayoutBoxType::InlineNode, // H1'in üst üste binmesini önlemek için geçici olarak BlockNode yapıldı
-                crate::css::enums::Display::None => LayoutBoxType::BlockNode,
-            }
-        },
-        NodeType::Text(_) => LayoutBoxType::AnonymousBlock, // Metin düğümleri için AnonymousBlock
-    });
-    layout_box.styled_node = Some(styled_node);
-
-    for child_styled_node in &styled_node.children {
-        layout_box.children.push(build_layout_tree(child_styled_node));
-    }
-
-    layout_box
+    paint_to_image(&display_list, &fonts, width, height, "output.png");
 }
@@ -4,11 +4,12 @@
 
 use crate::css::enums::Color;
 use crate::layout::structs::Rect;
+use crate::font::structs::FontQuery;
 
 /// Represents a display command to be rendered
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum Command {
     SolidColor(Color, Rect),
-    Text(String, Rect, Color, f32),
+    Text(String, Rect, Color, FontQuery),
 }
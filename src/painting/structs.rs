@@ -3,10 +3,11 @@
 //! This module handles converting layout boxes into display commands and rendering to images.
 
 use super::enums::Command;
-use crate::layout::structs::{LayoutBox};
+use crate::layout::enums::LayoutBoxType;
+use crate::layout::structs::{LayoutBox, Rect};
 use crate::css::enums::{Value, Color};
-use crate::html::enums::NodeType;
-use rusttype::{point, Font, Scale};
+use crate::font::structs::{em_scale, run_width, FontDatabase, FontQuery};
+use rusttype::point;
 
 /// Represents a list of display commands to be rendered
 #[derive(Debug, Default)]
@@ -21,47 +22,51 @@ pub fn build_display_list(layout_root: &LayoutBox) -> DisplayList {
 }
 
 fn render_layout_box(list: &mut DisplayList, layout_box: &LayoutBox) {
+    // visibility: hidden/collapse kutunun yerini korur ama kendisi çizilmez; çocuklar görünür olabilir
+    let visible = !layout_box.styled_node.is_some_and(|styled_node| {
+        matches!(styled_node.get_property("visibility"), Some(Value::Keyword(keyword)) if keyword == "hidden" || keyword == "collapse")
+    });
+
     // 1. ÖNCE ARKA PLAN RENGİNİ ÇİZ (en altta olmalı)
-    if let Some(styled_node) = layout_box.styled_node {
-        if let Some(Value::Color(color)) = styled_node.get_property("background-color") {
-            list.commands.push(Command::SolidColor(color.clone(), layout_box.dimensions.border_box()));
-        }
+    if visible
+        && let Some(styled_node) = layout_box.styled_node
+        && let Some(Value::Color(color)) = styled_node.get_property("background-color") {
+        list.commands.push(Command::SolidColor(color.clone(), layout_box.dimensions.border_box()));
     }
 
-    // 2. SONRA KENARLIKLARI ÇİZ
-    if layout_box.dimensions.border.top > 0.0 || layout_box.dimensions.border.bottom > 0.0 ||
-       layout_box.dimensions.border.left > 0.0 || layout_box.dimensions.border.right > 0.0 {
-        if let Some(styled_node) = layout_box.styled_node {
-            if let Some(Value::Color(border_color)) = styled_node.get_property("border-color") {
-                let border_box = layout_box.dimensions.border_box();
-                // Üst kenarlık
-                list.commands.push(Command::SolidColor(border_color.clone(), crate::layout::structs::Rect {
-                    x: border_box.x,
-                    y: border_box.y,
-                    width: border_box.width,
-                    height: layout_box.dimensions.border.top,
-                }));
-                // Alt kenarlık
-                list.commands.push(Command::SolidColor(border_color.clone(), crate::layout::structs::Rect {
-                    x: border_box.x,
-                    y: border_box.y + border_box.height - layout_box.dimensions.border.bottom,
-                    width: border_box.width,
-                    height: layout_box.dimensions.border.bottom,
-                }));
-                // Sol kenarlık
-                list.commands.push(Command::SolidColor(border_color.clone(), crate::layout::structs::Rect {
-                    x: border_box.x,
-                    y: border_box.y + layout_box.dimensions.border.top,
-                    width: layout_box.dimensions.border.left,
-                    height: border_box.height - layout_box.dimensions.border.top - layout_box.dimensions.border.bottom,
-                }));
-                // Sağ kenarlık
-                list.commands.push(Command::SolidColor(border_color.clone(), crate::layout::structs::Rect {
-                    x: border_box.x + border_box.width - layout_box.dimensions.border.right,
-                    y: border_box.y + layout_box.dimensions.border.top,
-                    width: layout_box.dimensions.border.right,
-                    height: border_box.height - layout_box.dimensions.border.top - layout_box.dimensions.border.bottom,
-                }));
+    // 2. SONRA KENARLIKLARI ÇİZ (her kenar kendi rengiyle)
+    if visible && let Some(styled_node) = layout_box.styled_node {
+        let border = &layout_box.dimensions.border;
+        let border_box = layout_box.dimensions.border_box();
+        let sides = [
+            // Üst kenarlık
+            ("top", Rect { x: border_box.x, y: border_box.y, width: border_box.width, height: border.top }),
+            // Alt kenarlık
+            ("bottom", Rect {
+                x: border_box.x,
+                y: border_box.y + border_box.height - border.bottom,
+                width: border_box.width,
+                height: border.bottom,
+            }),
+            // Sol kenarlık
+            ("left", Rect {
+                x: border_box.x,
+                y: border_box.y + border.top,
+                width: border.left,
+                height: border_box.height - border.top - border.bottom,
+            }),
+            // Sağ kenarlık
+            ("right", Rect {
+                x: border_box.x + border_box.width - border.right,
+                y: border_box.y + border.top,
+                width: border.right,
+                height: border_box.height - border.top - border.bottom,
+            }),
+        ];
+        for (side, rect) in sides {
+            if rect.width > 0.0 && rect.height > 0.0
+                && let Some(Value::Color(color)) = styled_node.get_property(&format!("border-{}-color", side)) {
+                list.commands.push(Command::SolidColor(color.clone(), rect));
             }
         }
     }
@@ -71,29 +76,21 @@ fn render_layout_box(list: &mut DisplayList, layout_box: &LayoutBox) {
         render_layout_box(list, child);
     }
 
-    // 4. EN SON METİN DÜĞÜMLERİNİ ÇİZ (en üstte olmalı)
-    let mut default_color = Color::RGBA(0, 0, 0, 1.0);
-    let mut default_font_size = 16.0;
-    if let Some(styled_node) = layout_box.styled_node {
-        if let Some(Value::Color(color)) = styled_node.get_property("color") {
-            default_color = color.clone();
-        }
-        if let Some(Value::Length(font_size, _)) = styled_node.get_property("font-size") {
-            default_font_size = font_size.clone();
-        }
-        if styled_node.children.len() > 0 {
-            if let NodeType::Text(ref text) = styled_node.children[0].node.node_type {
-                list.commands.push(Command::Text(text.to_string(), layout_box.dimensions.content.clone(), default_color.clone(), default_font_size.clone()));
-            } 
-        }
+    // 4. EN SON METİN PARÇALARINI ÇİZ (en üstte olmalı)
+    if visible
+        && let LayoutBoxType::TextFragment(text) = &layout_box.box_type
+        && let Some(styled_node) = layout_box.styled_node {
+        let color = match styled_node.get_property("color") {
+            Some(Value::Color(color)) => color.clone(),
+            _ => Color::RGBA(0, 0, 0, 1.0),
+        };
+        let font = FontQuery::from_styled_node(styled_node);
+        list.commands.push(Command::Text(text.clone(), layout_box.dimensions.content.clone(), color, font));
     }
 }
 
-pub fn paint_to_image(display_list: &DisplayList, width: u32, height: u32, filename: &str) {
+pub fn paint_to_image(display_list: &DisplayList, fonts: &FontDatabase, width: u32, height: u32, filename: &str) {
     let mut img = image::RgbaImage::new(width, height);
-    // Fontu yükle
-    let font_data = include_bytes!("../../Arial.ttf"); // Kök dizinde Arial.ttf olduğunu varsayıyoruz
-    let font = Font::try_from_bytes(font_data as &[u8]).expect("Failed to load font");
     // Arka planı beyaza boya
     for x in 0..width {
         for y in 0..height {
@@ -104,10 +101,7 @@ pub fn paint_to_image(display_list: &DisplayList, width: u32, height: u32, filen
     for command in &display_list.commands {
         match command {
             Command::SolidColor(color, rect) => {
-                let rgba_color = match color {
-                    Color::RGBA(r, g, b, a) => image::Rgba([*r, *g, *b, (a * 255.0) as u8]),
-                    _ => image::Rgba([0, 0, 0, 255]), // Desteklenmeyen renk tipi için varsayılan siyah
-                };
+                let rgba_color = to_image_color(color);
 
                 let x_start = rect.x.round() as u32;
                 let y_start = rect.y.round() as u32;
@@ -116,41 +110,29 @@ pub fn paint_to_image(display_list: &DisplayList, width: u32, height: u32, filen
 
                 for x in x_start.min(width)..x_end.min(width) {
                     for y in y_start.min(height)..y_end.min(height) {
-                        img.put_pixel(x, y, rgba_color);
+                        blend_pixel(&mut img, x, y, rgba_color, 1.0);
                     }
                 }
             }
-            Command::Text(text, rect, color, font_size) => {
-                let rgba_color = match color {
-                    Color::RGBA(r, g, b, a) => image::Rgba([*r, *g, *b, (a * 255.0) as u8]),
-                    _ => image::Rgba([0, 0, 0, 255]), // Desteklenmeyen renk tipi için varsayılan siyah
-                };
-                let scale = Scale::uniform(*font_size);
-                let v_metrics = font.v_metrics(scale);
-                let offset = point(rect.x, rect.y + v_metrics.ascent);
-                for glyph in font.layout(&text, scale, offset) {
-                    if let Some(bounding_box) = glyph.pixel_bounding_box() {
-
-                        glyph.draw(|x, y, v| {
-                            let x = x + bounding_box.min.x as u32;
-                            let y = y + bounding_box.min.y as u32;
-                            if x < width && y < height {
-                                let source_alpha = rgba_color[3] as f32 / 255.0;
-                                let effective_source_alpha = v * source_alpha;
-
-                                let existing_pixel = img.get_pixel(x, y).0;
-                                let target_alpha = existing_pixel[3] as f32 / 255.0;
-
-                                // Basit alpha blending
-                                let blended_r = (existing_pixel[0] as f32 * (1.0 - effective_source_alpha) + rgba_color[0] as f32 * effective_source_alpha) as u8;
-                                let blended_g = (existing_pixel[1] as f32 * (1.0 - effective_source_alpha) + rgba_color[1] as f32 * effective_source_alpha) as u8;
-                                let blended_b = (existing_pixel[2] as f32 * (1.0 - effective_source_alpha) + rgba_color[2] as f32 * effective_source_alpha) as u8;
-                                let blended_a = (target_alpha + effective_source_alpha * (1.0 - target_alpha)) as u8 * 255;
-                                // Arka plan tamamen opak olduğu için, hedef pikselin alfa değeri her zaman 255 olacak.
-                                img.put_pixel(x, y, image::Rgba([blended_r, blended_g, blended_b, blended_a]));
-                            }
-                        });
+            Command::Text(text, rect, color, query) => {
+                let rgba_color = to_image_color(color);
+                // Metin parçasının kutusu içerik alanıdır; taban çizgisi üstünden yükselim kadar aşağıdadır
+                let baseline = rect.y + fonts.measure(text, query).ascent;
+                let mut x = rect.x;
+                // Her parça kendi fontuyla çizilir; sonraki parça önceki parçanın bittiği yerden başlar
+                for (font, run) in fonts.runs(text, query) {
+                    for glyph in font.layout(&run, em_scale(font, query.size), point(x, baseline)) {
+                        if let Some(bounding_box) = glyph.pixel_bounding_box() {
+                            glyph.draw(|gx, gy, v| {
+                                let px = gx as i32 + bounding_box.min.x;
+                                let py = gy as i32 + bounding_box.min.y;
+                                if px >= 0 && py >= 0 && (px as u32) < width && (py as u32) < height {
+                                    blend_pixel(&mut img, px as u32, py as u32, rgba_color, v);
+                                }
+                            });
+                        }
                     }
+                    x += run_width(font, &run, query.size);
                 }
             }
         }
@@ -158,3 +140,72 @@ pub fn paint_to_image(display_list: &DisplayList, width: u32, height: u32, filen
 
     img.save(filename).expect("Failed to save image");
 }
+
+// CSS rengini sRGB'ye çevirip görüntü pikseline dönüştürür
+fn to_image_color(color: &Color) -> image::Rgba<u8> {
+    let (r, g, b, a) = color.to_srgb();
+    image::Rgba([r, g, b, (a * 255.0).round() as u8])
+}
+
+// Kaynak rengi, kapsama oranı (coverage) ile çarpılmış alfa değeriyle mevcut piksel üzerine karıştırır
+fn blend_pixel(img: &mut image::RgbaImage, x: u32, y: u32, color: image::Rgba<u8>, coverage: f32) {
+    let effective_source_alpha = coverage * color[3] as f32 / 255.0;
+    if effective_source_alpha <= 0.0 {
+        return;
+    }
+
+    let existing_pixel = img.get_pixel(x, y).0;
+    let target_alpha = existing_pixel[3] as f32 / 255.0;
+
+    // Basit alpha blending
+    let blend = |existing: u8, source: u8| (existing as f32 * (1.0 - effective_source_alpha) + source as f32 * effective_source_alpha) as u8;
+    let blended_a = ((target_alpha + effective_source_alpha * (1.0 - target_alpha)) * 255.0) as u8;
+    img.put_pixel(x, y, image::Rgba([blend(existing_pixel[0], color[0]), blend(existing_pixel[1], color[1]), blend(existing_pixel[2], color[2]), blended_a]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css::media::MediaEnvironment;
+    use crate::css::parser::Parser as CssParser;
+    use crate::html::parser::Parser as HtmlParser;
+    use crate::layout::structs::Dimensions;
+    use crate::layout::tree::build_layout_tree;
+    use crate::style::structs::style_tree;
+
+    // Belgeyi 800x600 görüntü alanında yerleştirir; çizilen metinleri ve dolgu renklerini sırasıyla döndürür
+    fn painted(html: &str, css: &str) -> (Vec<String>, Vec<[u8; 3]>) {
+        let dom = HtmlParser::new(html.to_string()).parse();
+        let stylesheets = vec![CssParser::new(css.to_string()).parse()];
+        let mut viewport = Dimensions::default();
+        viewport.content.width = 800.0;
+        viewport.content.height = 600.0;
+        let root = style_tree(&dom, &stylesheets, &viewport, &MediaEnvironment::default());
+        let mut layout_root = build_layout_tree(&root);
+        layout_root.layout(viewport, &FontDatabase::default());
+        let (mut texts, mut fills) = (vec![], vec![]);
+        for command in build_display_list(&layout_root).commands {
+            match command {
+                Command::Text(text, ..) => texts.push(text),
+                Command::SolidColor(color, _) => {
+                    let (r, g, b, _) = color.to_srgb();
+                    fills.push([r, g, b]);
+                }
+            }
+        }
+        (texts, fills)
+    }
+
+    #[test]
+    fn hidden_boxes_are_not_painted_but_visible_descendants_are() {
+        let css = "div { background-color: red; border-top: 1px solid red } p { visibility: visible; background-color: blue }";
+        let (texts, fills) = painted("<div><p>bb</p>aa<span>cc</span></div>", &format!("{} div {{ visibility: hidden }}", css));
+        assert_eq!(texts, vec!["bb"]);
+        assert_eq!(fills, vec![[0, 0, 255]]);
+        let (texts, _) = painted("<div><p>bb</p>aa<span>cc</span></div>", &format!("{} div {{ visibility: collapse }} span {{ visibility: visible }}", css));
+        assert_eq!(texts, vec!["bb", "cc"]);
+        let (texts, fills) = painted("<div><p>bb</p>aa</div>", css);
+        assert_eq!(texts, vec!["bb", "aa"]);
+        assert_eq!(fills.len(), 3);
+    }
+}
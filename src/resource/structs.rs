@@ -0,0 +1,56 @@
+//! Resource loading module
+//!
+//! This module loads external resources (stylesheets, fonts) referenced by URLs,
+//! resolving relative URLs against the location of the referring document.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Loads the contents of a resource from an absolute location
+pub trait ResourceLoader {
+    fn load(&self, location: &str) -> Option<Vec<u8>>;
+}
+
+/// Loads resources from the local file system; `file://` URLs are accepted as plain paths
+#[derive(Debug, Clone, Default)]
+pub struct FileLoader;
+
+impl ResourceLoader for FileLoader {
+    fn load(&self, location: &str) -> Option<Vec<u8>> {
+        std::fs::read(to_path(location)).ok()
+    }
+}
+
+// `file://` önekini atarak URL'i dosya yoluna çevirir
+fn to_path(location: &str) -> PathBuf {
+    PathBuf::from(location.strip_prefix("file://").unwrap_or(location))
+}
+
+/// Resolves a possibly relative URL against the location of the referring resource
+pub fn resolve_url(base: Option<&str>, url: &str) -> String {
+    // Şemalı (örn: file://) ya da mutlak yollar olduğu gibi kullanılır
+    if url.contains("://") || Path::new(url).is_absolute() {
+        return url.to_string();
+    }
+    match base {
+        Some(base) => {
+            let directory = to_path(base).parent().map(Path::to_path_buf).unwrap_or_default();
+            normalize(&directory.join(url)).to_string_lossy().into_owned()
+        }
+        None => url.to_string(),
+    }
+}
+
+// `.` ve `..` bileşenlerini dosya sistemine bakmadan çözer; aynı dosyaya giden yollar aynı olur
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if matches!(normalized.components().next_back(), Some(Component::Normal(_))) => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -1,2 +1,3 @@
 pub mod enums;
 pub mod structs;
+pub mod variables;
@@ -3,30 +3,263 @@
 //! This module handles the creation of styled nodes by applying CSS rules to HTML nodes.
 
 use std::collections::HashMap;
-use crate::css::enums::Value;
+use crate::css::enums::{Value, Origin, Specificity, Color, Unit, Display};
+use crate::css::structs::{StyleSheet, Rule};
 use crate::html::structs::{Node, ElementData};
 use crate::html::enums::NodeType;
 use lazy_static::lazy_static;
-use crate::css::enums::Display;
+use crate::css::parser::{Parser as CssParser, parse_value};
+use crate::css::shorthand::expand;
+use crate::css::math::MathKind;
+use crate::css::media::MediaEnvironment;
+use super::variables::{is_custom_property, resolve_custom_properties, substitute};
+use crate::layout::structs::Dimensions;
 
 lazy_static! {
-    static ref DEFAULT_DISPLAY_STYLES: HashMap<String, Display> = {
-        let mut m = HashMap::new();
-        m.insert("div".to_string(), Display::Block);
-        m.insert("p".to_string(), Display::Block);
-        m.insert("h1".to_string(), Display::Block);
-        m.insert("span".to_string(), Display::Inline);
-        // Diğer varsayılan display değerlerini buraya ekleyebilirsiniz
-        m
+    // Tarayıcı varsayılanlarını tanımlayan kullanıcı ajanı stil sayfası, yalnızca bir kez ayrıştırılır
+    static ref USER_AGENT_STYLESHEET: StyleSheet = {
+        let mut stylesheet = CssParser::new(include_str!("ua.css").to_string()).parse();
+        stylesheet.origin = Origin::UserAgent;
+        stylesheet
     };
+
+    // Desteklenen özelliklerin kalıtım davranışı ve başlangıç değerleri
+    static ref PROPERTY_REGISTRY: HashMap<&'static str, PropertyInfo> = {
+        let keyword = |k: &str| Value::Keyword(k.to_string());
+        let px = |v: f32| Value::Length(v, Unit::Px);
+        // Dördüncü alan anahtar kelimeler dışında kabul edilen değer türleri, beşincisi kabul edilen anahtar kelimelerdir;
+        // `None` her tanımlayıcıyı kabul eder (yazı tipi adları gibi)
+        let properties: Vec<(&str, bool, Value, &'static [ValueType], Keywords)> = vec![
+            // Kalıtılan özellikler
+            ("color", true, Value::Color(Color::RGBA(0, 0, 0, 1.0)), COLOR, Some(&[])),
+            ("font-family", true, keyword("sans-serif"), KEYWORDS, None),
+            ("font-size", true, px(16.0), LENGTH_PERCENTAGE, Some(&["xx-small", "x-small", "small", "medium", "large", "x-large", "xx-large", "xxx-large", "larger", "smaller"])),
+            ("font-weight", true, keyword("normal"), &[ValueType::Number], Some(&["normal", "bold", "bolder", "lighter"])),
+            ("font-style", true, keyword("normal"), KEYWORDS, Some(&["normal", "italic", "oblique"])),
+            ("font-variant", true, keyword("normal"), KEYWORDS, Some(&["normal", "small-caps"])),
+            ("font-stretch", true, keyword("normal"), &[ValueType::Percentage], Some(&["normal", "ultra-condensed", "extra-condensed", "condensed", "semi-condensed", "semi-expanded", "expanded", "extra-expanded", "ultra-expanded"])),
+            ("line-height", true, keyword("normal"), &[ValueType::Number, ValueType::Length, ValueType::Percentage], Some(&["normal"])),
+            ("text-align", true, keyword("start"), KEYWORDS, Some(&["left", "right", "center", "justify", "start", "end"])),
+            ("text-indent", true, px(0.0), LENGTH_PERCENTAGE, Some(&[])),
+            ("direction", true, keyword("ltr"), KEYWORDS, Some(&["ltr", "rtl"])),
+            ("white-space", true, keyword("normal"), KEYWORDS, Some(&["normal", "nowrap", "pre", "pre-wrap", "pre-line", "break-spaces"])),
+            ("word-break", true, keyword("normal"), KEYWORDS, Some(&["normal", "break-all", "keep-all", "break-word"])),
+            ("overflow-wrap", true, keyword("normal"), KEYWORDS, Some(&["normal", "break-word", "anywhere"])),
+            ("letter-spacing", true, keyword("normal"), LENGTH, Some(&["normal"])),
+            ("word-spacing", true, keyword("normal"), LENGTH, Some(&["normal"])),
+            ("visibility", true, keyword("visible"), KEYWORDS, Some(&["visible", "hidden", "collapse"])),
+            ("list-style-type", true, keyword("disc"), KEYWORDS, None),
+            // Kalıtılmayan özellikler
+            ("display", false, Value::Display(Display::Inline), KEYWORDS, Some(&[])),
+            ("width", false, keyword("auto"), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("height", false, keyword("auto"), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("min-width", false, px(0.0), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("min-height", false, px(0.0), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("max-width", false, keyword("none"), LENGTH_PERCENTAGE, Some(&["none"])),
+            ("max-height", false, keyword("none"), LENGTH_PERCENTAGE, Some(&["none"])),
+            ("margin-top", false, px(0.0), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("margin-right", false, px(0.0), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("margin-bottom", false, px(0.0), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("margin-left", false, px(0.0), LENGTH_PERCENTAGE, Some(&["auto"])),
+            ("padding-top", false, px(0.0), LENGTH_PERCENTAGE, Some(&[])),
+            ("padding-right", false, px(0.0), LENGTH_PERCENTAGE, Some(&[])),
+            ("padding-bottom", false, px(0.0), LENGTH_PERCENTAGE, Some(&[])),
+            ("padding-left", false, px(0.0), LENGTH_PERCENTAGE, Some(&[])),
+            ("border-top-width", false, px(3.0), LENGTH, Some(&["thin", "medium", "thick"])),
+            ("border-right-width", false, px(3.0), LENGTH, Some(&["thin", "medium", "thick"])),
+            ("border-bottom-width", false, px(3.0), LENGTH, Some(&["thin", "medium", "thick"])),
+            ("border-left-width", false, px(3.0), LENGTH, Some(&["thin", "medium", "thick"])),
+            ("border-top-style", false, keyword("none"), KEYWORDS, Some(&["none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset"])),
+            ("border-right-style", false, keyword("none"), KEYWORDS, Some(&["none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset"])),
+            ("border-bottom-style", false, keyword("none"), KEYWORDS, Some(&["none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset"])),
+            ("border-left-style", false, keyword("none"), KEYWORDS, Some(&["none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset"])),
+            ("border-top-color", false, keyword("currentcolor"), COLOR, Some(&[])),
+            ("border-right-color", false, keyword("currentcolor"), COLOR, Some(&[])),
+            ("border-bottom-color", false, keyword("currentcolor"), COLOR, Some(&[])),
+            ("border-left-color", false, keyword("currentcolor"), COLOR, Some(&[])),
+            ("background-color", false, Value::Color(Color::RGBA(0, 0, 0, 0.0)), COLOR, Some(&[])),
+            // Görüntü değerleri (`url()`, gradyanlar) metin olarak saklanır
+            ("background-image", false, keyword("none"), KEYWORDS, None),
+            ("background-repeat", false, keyword("repeat"), &[ValueType::List], Some(&["repeat", "repeat-x", "repeat-y", "no-repeat", "space", "round"])),
+            ("background-position", false, Value::List(vec![Value::Length(0.0, Unit::Percent), Value::Length(0.0, Unit::Percent)]), &[ValueType::Length, ValueType::Percentage, ValueType::List], Some(&["left", "center", "right", "top", "bottom"])),
+            ("text-decoration", false, keyword("none"), &[ValueType::Color, ValueType::List], Some(&["none", "underline", "overline", "line-through"])),
+            ("vertical-align", false, keyword("baseline"), LENGTH_PERCENTAGE, Some(&["baseline", "sub", "super", "text-top", "text-bottom", "middle", "top", "bottom"])),
+        ];
+        properties.into_iter()
+            .map(|(name, inherited, initial, types, keywords)| (name, PropertyInfo { inherited, initial, types, keywords }))
+            .collect()
+    };
+}
+
+/// The kinds of values a property accepts besides its keywords
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueType {
+    Length,
+    Percentage,
+    Number,
+    Color,
+    List, // Her bileşeni özelliğin kabul ettiği bir değer olan, boşlukla ayrılmış liste
+}
+
+const KEYWORDS: &[ValueType] = &[];
+const LENGTH: &[ValueType] = &[ValueType::Length];
+const LENGTH_PERCENTAGE: &[ValueType] = &[ValueType::Length, ValueType::Percentage];
+const COLOR: &[ValueType] = &[ValueType::Color];
+
+/// Describes how a CSS property takes part in inheritance and which values it accepts
+#[derive(Debug, Clone)]
+pub struct PropertyInfo {
+    pub inherited: bool,
+    pub initial: Value,
+    pub types: &'static [ValueType],
+    pub keywords: Keywords,
+}
+
+/// Keywords a property accepts; `None` accepts any identifier
+pub type Keywords = Option<&'static [&'static str]>;
+
+// Özelliğin kalıtılıp kalıtılmadığını döndürür; özel özellikler kalıtılır, bilinmeyen özellikler kalıtılmaz
+fn is_inherited_property(property_name: &str) -> bool {
+    is_custom_property(property_name) || PROPERTY_REGISTRY.get(property_name).is_some_and(|info| info.inherited)
+}
+
+/// Returns true for properties the engine implements: registered longhands and custom properties
+pub fn is_supported_property(property_name: &str) -> bool {
+    is_custom_property(property_name) || PROPERTY_REGISTRY.contains_key(property_name)
+}
+
+// Anahtar kelime bir CSS genel anahtar kelimesi ya da özelliğin kabul ettiği anahtar kelimelerden biri mi
+fn is_supported_keyword(property_name: &str, keyword: &str) -> bool {
+    if ["inherit", "initial", "unset", "revert"].iter().any(|wide| keyword.eq_ignore_ascii_case(wide)) {
+        return true;
+    }
+    PROPERTY_REGISTRY.get(property_name).is_some_and(|info| {
+        info.keywords.is_none_or(|keywords| keywords.iter().any(|allowed| keyword.eq_ignore_ascii_case(allowed)))
+    })
+}
+
+/// Returns true if the value is valid for the property: one of its keywords or a value of a type it
+/// accepts. Values containing `var()` are only checked after substitution.
+pub fn is_valid_value(property_name: &str, value: &Value) -> bool {
+    let Some(info) = PROPERTY_REGISTRY.get(property_name) else {
+        return is_custom_property(property_name);
+    };
+    let accepts = |value_type| info.types.contains(&value_type);
+    match value {
+        // `currentcolor` renk kabul eden her özellikte geçerlidir
+        Value::Keyword(keyword) if keyword.eq_ignore_ascii_case("currentcolor") => accepts(ValueType::Color),
+        Value::Keyword(keyword) => is_supported_keyword(property_name, keyword),
+        Value::Length(_, Unit::Percent) => accepts(ValueType::Percentage),
+        Value::Length(..) => accepts(ValueType::Length),
+        Value::Number(_) => accepts(ValueType::Number),
+        Value::Color(_) => accepts(ValueType::Color),
+        Value::Display(_) => property_name == "display",
+        Value::Math(expr) => match expr.kind() {
+            Some(MathKind::Number) => accepts(ValueType::Number),
+            Some(MathKind::Length) => accepts(ValueType::Length) || accepts(ValueType::Percentage),
+            None => false,
+        },
+        // Liste bileşenleri iç içe liste ya da CSS genel anahtar kelimesi olamaz
+        Value::List(values) => accepts(ValueType::List) && values.iter().all(|value| {
+            !matches!(value, Value::List(_))
+                && !["inherit", "initial", "unset", "revert"].iter().any(|keyword| is_css_wide_keyword(value, keyword))
+                && is_valid_value(property_name, value)
+        }),
+        Value::Tokens(_) | Value::PendingSubstitution(..) => true,
+    }
+}
+
+// Özelliğin başlangıç (initial) değerini döndürür
+fn initial_value(property_name: &str) -> Option<Value> {
+    PROPERTY_REGISTRY.get(property_name).map(|info| info.initial.clone())
+}
+
+/// Environment used to evaluate media queries and turn relative lengths into pixels at computed-value time
+#[derive(Debug, Clone, Copy)]
+pub struct ComputeContext {
+    pub viewport_width: f32,
+    pub viewport_height: f32,
+    pub root_font_size: f32,
+    pub media: MediaEnvironment,
+}
+
+impl ComputeContext {
+    // Bir uzunluğu, verilen font-size ve görüntü alanına göre piksele çevirir.
+    // Yüzdeler yalnızca font-size ve line-height için buraya gelir; ikisi de font-size'a göredir.
+    pub fn resolve_length(&self, value: f32, unit: &Unit, font_size: f32) -> f32 {
+        match unit {
+            Unit::Px => value,
+            Unit::Em => value * font_size,
+            Unit::Percent => value * font_size / 100.0,
+            Unit::Rem => value * self.root_font_size,
+            Unit::Vw => value * self.viewport_width / 100.0,
+            Unit::Vh => value * self.viewport_height / 100.0,
+            Unit::Vmin => value * self.viewport_width.min(self.viewport_height) / 100.0,
+            Unit::Vmax => value * self.viewport_width.max(self.viewport_height) / 100.0,
+        }
+    }
+
+    // font-size değerini ebeveynin font-size'ına göre piksele çevirir
+    fn resolve_font_size(&self, value: &Value, parent_font_size: f32) -> Option<f32> {
+        match value {
+            // font-size içindeki em, ebeveynin font-size'ına göredir
+            Value::Length(length, unit) => Some(self.resolve_length(*length, unit, parent_font_size)),
+            Value::Math(expr) => expr.evaluate(&|length, unit| Some(self.resolve_length(length, &unit, parent_font_size))),
+            Value::Keyword(keyword) => match keyword.to_lowercase().as_str() {
+                "xx-small" => Some(9.0),
+                "x-small" => Some(10.0),
+                "small" => Some(13.0),
+                "medium" => Some(16.0),
+                "large" => Some(18.0),
+                "x-large" => Some(24.0),
+                "xx-large" => Some(32.0),
+                "xxx-large" => Some(48.0),
+                "smaller" => Some(parent_font_size / 1.2),
+                "larger" => Some(parent_font_size * 1.2),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+// Hesaplanmış bir font-size değerinden piksel değerini okur
+fn font_size_px(value: Option<&Value>) -> f32 {
+    match value {
+        Some(Value::Length(size, _)) => *size,
+        _ => 16.0,
+    }
+}
+
+// font-weight değerini sayıya çevirir; göreli anahtar kelimeler ebeveynin kalınlığına göre çözülür
+fn font_weight_number(value: Option<&Value>, parent_weight: f32) -> f32 {
+    match value {
+        Some(Value::Number(weight)) => *weight,
+        Some(Value::Keyword(keyword)) => match keyword.to_lowercase().as_str() {
+            "bold" => 700.0,
+            "bolder" if parent_weight < 350.0 => 400.0,
+            "bolder" if parent_weight < 550.0 => 700.0,
+            "bolder" => parent_weight.max(900.0),
+            "lighter" if parent_weight < 100.0 => parent_weight,
+            "lighter" if parent_weight < 550.0 => 100.0,
+            "lighter" if parent_weight < 750.0 => 400.0,
+            "lighter" => 700.0,
+            _ => 400.0,
+        },
+        _ => parent_weight,
+    }
+}
+
+// Değer belirtilen CSS genel anahtar kelimesi mi (inherit, initial, unset, revert)
+fn is_css_wide_keyword(value: &Value, keyword: &str) -> bool {
+    matches!(value, Value::Keyword(k) if k.eq_ignore_ascii_case(keyword))
 }
 
 /// Represents a node with computed styles applied
 #[derive(Debug, Clone)]
 pub struct StyledNode<'a> {
     pub node: &'a Node,
-    pub specified_values: PropertyMap,
-    pub computed_values: PropertyMap, // Yeni eklendi
+    pub computed_values: PropertyMap,
     pub children: Vec<StyledNode<'a>>,
 }
 
@@ -34,97 +267,619 @@ pub struct StyledNode<'a> {
 pub type PropertyMap = HashMap<String, Value>;
 
 impl<'a> StyledNode<'a> {
-    pub fn new(node: &'a Node, specified_values: PropertyMap, computed_values: PropertyMap, children: Vec<StyledNode<'a>>) -> StyledNode<'a> {
+    pub fn new(node: &'a Node, computed_values: PropertyMap, children: Vec<StyledNode<'a>>) -> StyledNode<'a> {
         StyledNode {
             node,
-            specified_values,
             computed_values,
             children,
         }
     }
-    // Stil ağacındaki bir özelliği Value olarak döndürür
+    // Stil ağacındaki bir özelliğin hesaplanmış değerini döndürür
     pub fn get_property(&self, name: &str) -> Option<&Value> {
-        // Önce doğrudan belirtilen değerlere bak
-        if let Some(value) = self.specified_values.get(name) {
-            Some(value)
-        } else {
-            // Yoksa hesaplanmış (miras alınmış) değerlere bak
-            self.computed_values.get(name)
+        self.computed_values.get(name)
+    }
+
+    /// Returns the computed `display` value; text nodes and elements without one are inline
+    pub fn display(&self) -> Display {
+        match self.get_property("display") {
+            Some(Value::Display(display)) => display.clone(),
+            _ => Display::Inline,
+        }
+    }
+}
+
+/// A rule matching an element, together with the keys used to order it in the cascade
+#[derive(Debug, Clone)]
+pub struct MatchedRule<'a> {
+    pub rule: &'a Rule,
+    pub origin: Origin,
+    pub specificity: Specificity,
+    pub source_order: usize,
+    pub layer_order: Vec<i64>,
+}
+
+// Katmanın kademe içindeki sırası: her düzeyde kardeşler arasındaki bildirim sırası.
+// Katmana doğrudan yazılan kurallar alt katmanlarını, katmansız kurallar tüm katmanları yener.
+fn layer_order(layer: Option<&str>, layers: &[&String]) -> Vec<i64> {
+    let mut order = vec![];
+    if let Some(layer) = layer {
+        let segments: Vec<&str> = layer.split('.').collect();
+        for end in 1..=segments.len() {
+            let parent = segments[..end - 1].join(".");
+            let name = segments[..end].join(".");
+            // Aynı üst katmana sahip, daha önce bildirilmiş kardeşlerin sayısı
+            let siblings = layers.iter()
+                .take_while(|candidate| ***candidate != name)
+                .filter(|candidate| candidate.rsplit_once('.').map_or("", |(candidate_parent, _)| candidate_parent) == parent)
+                .count();
+            order.push(siblings as i64);
         }
     }
+    order.push(i64::MAX);
+    order
 }
 
-// Bir DOM düğümüne uyan tüm CSS kurallarını bulur
-pub fn matching_rules<'a>(elem: &ElementData, stylesheet: &'a crate::css::structs::StyleSheet) -> Vec<&'a crate::css::structs::Rule> {
-    stylesheet.rules.iter().filter(|rule| {
-        rule.selectors.iter().any(|selector| selector.matches(elem))
-    }).collect()
+// Bir DOM düğümüne uyan tüm CSS kurallarını bulur; @media sorguları eşleşmeyen kurallar atlanır
+pub fn matching_rules<'a>(elem: &ElementData, stylesheets: &[&'a StyleSheet], context: &ComputeContext) -> Vec<MatchedRule<'a>> {
+    // Katman sırası tüm stil sayfalarındaki ilk bildirimlere göre belirlenir
+    let mut layers: Vec<&String> = vec![];
+    for layer in stylesheets.iter().flat_map(|stylesheet| stylesheet.layers.iter()) {
+        if !layers.contains(&layer) {
+            layers.push(layer);
+        }
+    }
+    stylesheets.iter()
+        .flat_map(|stylesheet| stylesheet.rules.iter().map(move |rule| (stylesheet.origin, rule)))
+        .enumerate()
+        .filter(|(_, (_, rule))| rule.media.iter()
+            .all(|media| media.matches(&context.media, context.viewport_width, context.viewport_height)))
+        .filter_map(|(source_order, (origin, rule))| {
+            // Kuralın özgüllüğü, elemana gerçekten uyan en özgül seçiciden gelir
+            rule.matching_specificity(elem).map(|specificity| MatchedRule {
+                rule,
+                origin,
+                specificity,
+                source_order,
+                layer_order: layer_order(rule.layer.as_deref(), &layers),
+            })
+        })
+        .collect()
 }
 
-// Stil ağacını DOM ağacından ve stil sayfasından oluşturan ana fonksiyon
-pub fn style_tree<'a>(root: &'a Node, stylesheet: &'a crate::css::structs::StyleSheet) -> StyledNode<'a> {
-    style_tree_recursive(root, stylesheet, &PropertyMap::new())
+// Stil ağacını DOM ağacından ve stil sayfalarından oluşturan ana fonksiyon
+pub fn style_tree<'a>(root: &'a Node, stylesheets: &'a [StyleSheet], viewport: &Dimensions, media: &MediaEnvironment) -> StyledNode<'a> {
+    // Kullanıcı ajanı stil sayfası her zaman ilk sırada yer alır
+    let mut all_stylesheets: Vec<&StyleSheet> = vec![&USER_AGENT_STYLESHEET];
+    all_stylesheets.extend(stylesheets.iter());
+    let context = ComputeContext {
+        viewport_width: viewport.content.width,
+        viewport_height: viewport.content.height,
+        root_font_size: font_size_px(initial_value("font-size").as_ref()),
+        media: *media,
+    };
+    style_tree_recursive(root, &all_stylesheets, None, context)
 }
 
 // Rekürsif yardımcı fonksiyon
 fn style_tree_recursive<'a>(
     node: &'a Node,
-    stylesheet: &'a crate::css::structs::StyleSheet,
-    parent_computed_styles: &PropertyMap, // Ebeveynin hesaplanmış stilleri
+    stylesheets: &[&'a StyleSheet],
+    parent_computed_styles: Option<&PropertyMap>, // Ebeveynin hesaplanmış stilleri, kök için None
+    context: ComputeContext,
 ) -> StyledNode<'a> {
     let specified_values = match node.node_type {
-        NodeType::Element(ref elem) => calculate_style_for_element(elem, stylesheet),
+        NodeType::Element(ref elem) => calculate_style_for_element(elem, stylesheets, &context),
         _ => HashMap::new(),
     };
 
-    let mut computed_values = parent_computed_styles.clone(); // Ebeveyn stillerini miras al
+    // Ebeveyn değeri; kök elemanda başlangıç değerine düşer
+    let parent_value = |prop: &str| match parent_computed_styles {
+        Some(parent) => parent.get(prop).cloned().or_else(|| initial_value(prop)),
+        None => initial_value(prop),
+    };
+
+    // Yalnızca kalıtılan özellikler ebeveynden çocuğa geçer
+    let mut computed_values: PropertyMap = PROPERTY_REGISTRY.iter()
+        .filter(|(_, info)| info.inherited)
+        .filter_map(|(name, _)| parent_value(name).map(|value| (name.to_string(), value)))
+        .collect();
+
+    // Özel özellikler her zaman kalıtılır
+    if let Some(parent) = parent_computed_styles {
+        computed_values.extend(parent.iter()
+            .filter(|(name, _)| is_custom_property(name))
+            .map(|(name, value)| (name.clone(), value.clone())));
+    }
+
+    // Belirtilen değeri CSS genel anahtar kelimelerini çözerek hesaplanan değerlere yazar
+    let apply = |computed_values: &mut PropertyMap, prop: &str, value: &Value| {
+        let computed = if is_css_wide_keyword(value, "inherit") {
+            parent_value(prop)
+        } else if is_css_wide_keyword(value, "initial") {
+            initial_value(prop)
+        } else if is_css_wide_keyword(value, "unset") {
+            if is_inherited_property(prop) { parent_value(prop) } else { initial_value(prop) }
+        } else {
+            Some(value.clone())
+        };
+        match computed {
+            Some(computed) => computed_values.insert(prop.to_string(), computed),
+            None => computed_values.remove(prop),
+        };
+    };
+
+    // Kendi belirtilen değerleri miras alınanları ezer: önce özel özellikler ve onların `var()` başvuruları çözülür
+    for (prop, value) in specified_values.iter().filter(|(prop, _)| is_custom_property(prop)) {
+        apply(&mut computed_values, prop, value);
+    }
+    resolve_custom_properties(&mut computed_values);
+
+    // `var()` içeren değerler yerine koyma sonrasında ayrıştırılır
+    for (prop, value) in specified_values.iter().filter(|(prop, _)| !is_custom_property(prop)) {
+        let (tokens, shorthand) = match value {
+            Value::Tokens(tokens) => (tokens, None),
+            Value::PendingSubstitution(shorthand, tokens) => (tokens, Some(shorthand)),
+            _ => {
+                apply(&mut computed_values, prop, value);
+                continue;
+            }
+        };
+        let substituted = substitute(tokens, &mut |name| match computed_values.get(name) {
+            Some(Value::Tokens(tokens)) => Some(tokens.clone()),
+            _ => None,
+        });
+        // Kısaltmanın uzun biçimi, yerine konmuş kısaltma değeri açılarak bulunur
+        let parsed = substituted.and_then(|tokens| match shorthand {
+            Some(shorthand) => expand(shorthand, &tokens)?.ok()?.into_iter()
+                .find(|(longhand, _)| longhand == prop)
+                .map(|(_, value)| value),
+            None => parse_value(prop, &tokens).ok().filter(|value| is_valid_value(prop, value)),
+        });
+        // Çözülemeyen değer hesaplanan değer aşamasında geçersizdir ve `unset` gibi davranır
+        let value = parsed.unwrap_or_else(|| Value::Keyword("unset".to_string()));
+        apply(&mut computed_values, prop, &value);
+    }
 
-    // Kalıtılabilir özellikleri ebeveyn'den al
-    for (prop, value) in parent_computed_styles.iter() {
-        if is_inheritable_property(prop) {
-            computed_values.insert(prop.clone(), value.clone());
+    // `currentcolor`: `color` özelliğinde ebeveynin rengi, diğer özelliklerde elemanın kendi rengi kullanılır
+    let is_current_color = |value: &Value| matches!(value, Value::Keyword(k) if k == "currentcolor");
+    if computed_values.get("color").is_some_and(is_current_color)
+        && let Some(color) = parent_value("color") {
+        computed_values.insert("color".to_string(), color);
+    }
+    if let Some(color) = computed_values.get("color").cloned() {
+        for value in computed_values.values_mut() {
+            replace_current_color(value, &color);
         }
     }
 
-    // Kendi belirtilen değerleri miras alınanları ezer
-    for (prop, value) in specified_values.iter() {
-        computed_values.insert(prop.clone(), value.clone());
+    // Göreli uzunlukları piksele çevir: önce ebeveyne göre font-size, sonra kendi font-size'ına göre diğerleri
+    let parent_font_size = font_size_px(parent_value("font-size").as_ref());
+    let font_size = computed_values.get("font-size")
+        .and_then(|value| context.resolve_font_size(value, parent_font_size))
+        .unwrap_or(parent_font_size);
+    computed_values.insert("font-size".to_string(), Value::Length(font_size, Unit::Px));
+    // font-weight sayıya çevrilir; `bolder` ve `lighter` ebeveynin kalınlığına göre çözülür
+    let parent_font_weight = font_weight_number(parent_value("font-weight").as_ref(), 400.0);
+    let font_weight = font_weight_number(computed_values.get("font-weight"), parent_font_weight);
+    computed_values.insert("font-weight".to_string(), Value::Number(font_weight));
+    for (prop, value) in computed_values.iter_mut() {
+        if prop == "font-size" {
+            continue;
+        }
+        // Kutu yüzdeleri kapsayan bloğa bağlıdır, düzen aşamasına kadar korunur
+        resolve_lengths(value, &context, font_size, prop == "line-height");
     }
 
+    // Kenarlık genişlikleri: anahtar kelimeler piksele döner, stili olmayan kenarlığın genişliği sıfırdır
+    for side in ["top", "right", "bottom", "left"] {
+        let style = computed_values.get(&format!("border-{}-style", side));
+        let has_border = !matches!(style, Some(Value::Keyword(k)) if matches!(k.to_lowercase().as_str(), "none" | "hidden"));
+        if let Some(width) = computed_values.get_mut(&format!("border-{}-width", side)) {
+            if !has_border {
+                *width = Value::Length(0.0, Unit::Px);
+            } else if let Value::Keyword(keyword) = width {
+                let px = match keyword.to_lowercase().as_str() {
+                    "thin" => 1.0,
+                    "thick" => 5.0,
+                    _ => 3.0,
+                };
+                *width = Value::Length(px, Unit::Px);
+            }
+        }
+    }
+
+    // rem birimi kök elemanın font-size'ına göredir
+    let child_context = match parent_computed_styles {
+        Some(_) => context,
+        None => ComputeContext { root_font_size: font_size, ..context },
+    };
+
     let children = node.children.iter()
-        .map(|child| style_tree_recursive(child, stylesheet, &computed_values)) // Alt düğümlere computed_values'ı geçir
+        .map(|child| style_tree_recursive(child, stylesheets, Some(&computed_values), child_context)) // Alt düğümlere computed_values'ı geçir
         .collect();
 
-    StyledNode::new(node, specified_values, computed_values, children) // computed_values'ı ekle
+    StyledNode::new(node, computed_values, children)
 }
 
-// Bir özelliğin kalıtılabilir olup olmadığını kontrol eden yardımcı fonksiyon
-fn is_inheritable_property(property_name: &str) -> bool {
-    match property_name {
-        "color" | "font-family" | "font-size" | "font-weight" | "line-height" => true,
-        _ => false,
+// Değerdeki (liste bileşenleri dahil) `currentcolor` anahtar kelimelerini elemanın rengiyle değiştirir
+fn replace_current_color(value: &mut Value, color: &Value) {
+    match value {
+        Value::Keyword(keyword) if keyword == "currentcolor" => *value = color.clone(),
+        Value::List(values) => {
+            for value in values.iter_mut() {
+                replace_current_color(value, color);
+            }
+        }
+        _ => {}
+    }
+}
+
+// Değerdeki (liste bileşenleri dahil) göreli uzunlukları piksele çevirir
+fn resolve_lengths(value: &mut Value, context: &ComputeContext, font_size: f32, resolve_percent: bool) {
+    match value {
+        Value::Length(length, unit) if *unit != Unit::Percent || resolve_percent => {
+            *value = Value::Length(context.resolve_length(*length, unit, font_size), Unit::Px);
+        }
+        Value::List(values) => {
+            for value in values.iter_mut() {
+                resolve_lengths(value, context, font_size, resolve_percent);
+            }
+        }
+        // Matematik ifadelerinde bilinen uzunluklar çözülür; yüzde içermeyen ifadeler tek bir değere indirgenir
+        Value::Math(expr) => {
+            let resolved = expr.map_lengths(&|length, unit| {
+                (unit != Unit::Percent || resolve_percent).then(|| context.resolve_length(length, &unit, font_size))
+            });
+            *value = match (resolved.evaluate(&|length, unit| (unit == Unit::Px).then_some(length)), resolved.kind()) {
+                (Some(number), Some(MathKind::Number)) => Value::Number(number),
+                (Some(length), _) => Value::Length(length, Unit::Px),
+                (None, _) => Value::Math(resolved),
+            };
+        }
+        _ => {}
+    }
+}
+
+// Köken ve `!important` bilgisine göre bildirimin kademe (cascade) seviyesini döndürür.
+// Önemli bildirimlerde köken sıralaması tersine döner.
+fn cascade_level(origin: Origin, important: bool) -> u8 {
+    match (origin, important) {
+        (Origin::UserAgent, false) => 0,
+        (Origin::User, false) => 1,
+        (Origin::Author, false) => 2,
+        (Origin::Author, true) => 3,
+        (Origin::User, true) => 4,
+        (Origin::UserAgent, true) => 5,
     }
 }
 
 // Bir element için stil özelliklerini hesaplar
-fn calculate_style_for_element(elem: &ElementData, stylesheet: &crate::css::structs::StyleSheet) -> PropertyMap {
+fn calculate_style_for_element(elem: &ElementData, stylesheets: &[&StyleSheet], context: &ComputeContext) -> PropertyMap {
     let mut properties = HashMap::new();
-    let mut rules = matching_rules(elem, stylesheet);
+    let rules = matching_rules(elem, stylesheets, context);
+
+    // Bildirimleri kademe sırasına koy: seviye, katman, özgüllük, kaynak sırası (en güçlü sona gelsin).
+    // Önemli bildirimlerde katman sırası tersine döner.
+    // Sıralama kararlı olduğu için aynı kural içindeki bildirimler yazıldıkları sırada kalır.
+    let mut declarations: Vec<_> = rules.iter()
+        .flat_map(|matched| matched.rule.declarations.iter().map(move |declaration| {
+            let layer_order = match declaration.important {
+                true => matched.layer_order.iter().map(|order| -order).collect(),
+                false => matched.layer_order.clone(),
+            };
+            (cascade_level(matched.origin, declaration.important), layer_order, matched.specificity, matched.source_order, matched.origin, declaration)
+        }))
+        .collect();
+    declarations.sort_by(|a, b| (a.0, &a.1, a.2, a.3).cmp(&(b.0, &b.1, b.2, b.3)));
+
+    // Her özellik için kademe sırasına göre tüm adayları topla; `revert` için gerekli
+    let mut candidates: HashMap<&str, Vec<(Origin, &Value)>> = HashMap::new();
+    for (_, _, _, _, origin, declaration) in declarations {
+        candidates.entry(declaration.property.as_str()).or_default().push((origin, &declaration.value));
+    }
+
+    for (property, entries) in candidates {
+        properties.insert(property.to_string(), resolve_revert(entries));
+    }
+    properties
+}
+
+// Kazanan değer `revert` ise kademeyi bir önceki kökene geri sarar.
+// Geri sarılacak köken kalmadıysa değer `unset` gibi davranır.
+fn resolve_revert(mut entries: Vec<(Origin, &Value)>) -> Value {
+    while let Some((origin, value)) = entries.pop() {
+        if !is_css_wide_keyword(value, "revert") {
+            return value.clone();
+        }
+        entries.retain(|(candidate_origin, _)| *candidate_origin < origin);
+    }
+    Value::Keyword("unset".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html::enums::AttrName;
+    use crate::html::parser::Parser as HtmlParser;
+
+    // Belgeyi stillendirir ve `id` özniteliği verilen elemanın hesaplanmış değerlerini döndürür
+    fn computed_values(html: &str, css: &str, id: &str) -> PropertyMap {
+        computed_values_with_origins(html, &[(Origin::Author, css)], id)
+    }
+
+    // Stil sayfaları verilen kökenlerle, verilen sırada kullanıcı ajanı stil sayfasının ardından uygulanır
+    fn computed_values_with_origins(html: &str, sheets: &[(Origin, &str)], id: &str) -> PropertyMap {
+        let dom = HtmlParser::new(html.to_string()).parse();
+        let stylesheets: Vec<StyleSheet> = sheets.iter()
+            .map(|(origin, css)| StyleSheet { origin: *origin, ..CssParser::new(css.to_string()).parse() })
+            .collect();
+        let mut viewport = Dimensions::default();
+        viewport.content.width = 800.0;
+        viewport.content.height = 600.0;
+        let root = style_tree(&dom, &stylesheets, &viewport, &MediaEnvironment::default());
+        fn find(node: &StyledNode, id: &str) -> Option<PropertyMap> {
+            if let NodeType::Element(element) = &node.node.node_type
+                && element.attributes.get(&AttrName::Id).is_some_and(|value| value == id) {
+                return Some(node.computed_values.clone());
+            }
+            node.children.iter().find_map(|child| find(child, id))
+        }
+        find(&root, id).expect("element not found")
+    }
 
-    // Varsayılan display stilini ekle, eğer CSS tarafından ezilmediyse
-    if !properties.contains_key("display") {
-        if let Some(default_display) = DEFAULT_DISPLAY_STYLES.get(&elem.tag_name.to_string()) {
-            properties.insert("display".to_string(), Value::Display(default_display.clone()));
+    fn srgb(value: Option<&Value>) -> Option<(u8, u8, u8, f32)> {
+        match value {
+            Some(Value::Color(color)) => Some(color.to_srgb()),
+            _ => None,
         }
     }
 
-    // Özgüllüğe göre sırala (en özgül sona gelsin)
-    rules.sort_by_key(|rule| rule.selectors[0].specificity());
+    #[test]
+    fn current_color_uses_the_element_color() {
+        let values = computed_values("<div id=\"t\"></div>", "div { color: red; border-top-color: currentColor }", "t");
+        assert_eq!(srgb(values.get("border-top-color")), Some((255, 0, 0, 1.0)));
+    }
+
+    #[test]
+    fn current_color_in_color_refers_to_the_parent() {
+        let values = computed_values("<div><p id=\"t\"></p></div>", "div { color: blue } p { color: currentcolor }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+    }
+
+    #[test]
+    fn current_color_inside_list_values_is_replaced() {
+        let values = computed_values("<div id=\"t\"></div>", "div { color: lime; text-decoration: underline currentcolor }", "t");
+        let Some(Value::List(components)) = values.get("text-decoration") else {
+            panic!("expected a list, got {:?}", values.get("text-decoration"));
+        };
+        assert!(matches!(&components[0], Value::Keyword(k) if k == "underline"));
+        assert_eq!(srgb(components.get(1)), Some((0, 255, 0, 1.0)));
+    }
 
-    for rule in &rules {
-        for declaration in &rule.declarations {
-            properties.insert(declaration.property.clone(), declaration.value.clone());
+    fn px(value: Option<&Value>) -> Option<f32> {
+        match value {
+            Some(Value::Length(length, Unit::Px)) => Some(*length),
+            _ => None,
         }
     }
-    properties
+
+    #[test]
+    fn shorthand_with_var_competes_by_specificity() {
+        let values = computed_values("<div id=\"t\" class=\"c\"></div>", ".c { margin: var(--m, 30px) } div { margin-top: 5px }", "t");
+        assert_eq!(px(values.get("margin-top")), Some(30.0));
+        assert_eq!(px(values.get("margin-left")), Some(30.0));
+    }
+
+    #[test]
+    fn shorthand_with_var_competes_by_source_order() {
+        let later_longhand = computed_values("<div id=\"t\"></div>", "div { margin: var(--m, 30px) } div { margin-top: 5px }", "t");
+        assert_eq!(px(later_longhand.get("margin-top")), Some(5.0));
+        assert_eq!(px(later_longhand.get("margin-bottom")), Some(30.0));
+        let later_shorthand = computed_values("<div id=\"t\"></div>", "div { margin-top: 5px } div { margin: var(--m, 30px) }", "t");
+        assert_eq!(px(later_shorthand.get("margin-top")), Some(30.0));
+        // Aynı bildirim bloğunda da yazılış sırası geçerlidir
+        let same_block = computed_values("<div id=\"t\"></div>", "div { margin: var(--m, 30px); margin-top: 5px }", "t");
+        assert_eq!(px(same_block.get("margin-top")), Some(5.0));
+    }
+
+    #[test]
+    fn shorthand_with_var_beats_user_agent_longhands() {
+        let values = computed_values("<html><body id=\"t\"></body></html>", "html { --gap: 20px } body { margin: var(--gap) }", "t");
+        assert_eq!(px(values.get("margin-top")), Some(20.0));
+        assert_eq!(px(values.get("margin-left")), Some(20.0));
+    }
+
+    #[test]
+    fn shorthand_with_var_keeps_importance() {
+        let values = computed_values("<div id=\"t\" class=\"c\"></div>", "div { margin: var(--m, 7px) !important } .c { margin-top: 5px }", "t");
+        assert_eq!(px(values.get("margin-top")), Some(7.0));
+    }
+
+    #[test]
+    fn shorthand_with_var_fills_every_longhand() {
+        let values = computed_values("<div id=\"t\"></div>", "div { --c: red; border: 2px solid var(--c) }", "t");
+        assert_eq!(px(values.get("border-top-width")), Some(2.0));
+        assert!(matches!(values.get("border-right-style"), Some(Value::Keyword(k)) if k == "solid"));
+        assert_eq!(srgb(values.get("border-bottom-color")), Some((255, 0, 0, 1.0)));
+    }
+
+    #[test]
+    fn invalid_shorthand_after_substitution_is_unset() {
+        let values = computed_values("<div id=\"t\"></div>", "div { margin-top: 5px } div { margin: var(--missing) }", "t");
+        assert_eq!(px(values.get("margin-top")), Some(0.0));
+        let values = computed_values("<div id=\"t\"></div>", "div { --m: 1px 2px 3px 4px 5px; margin: var(--m) }", "t");
+        assert_eq!(px(values.get("margin-left")), Some(0.0));
+    }
+
+    #[test]
+    fn origins_cascade_user_agent_then_user_then_author() {
+        let html = "<html><body id=\"t\"></body></html>";
+        let values = computed_values_with_origins(html, &[(Origin::User, "body { margin-top: 3px; color: blue }")], "t");
+        assert_eq!(px(values.get("margin-top")), Some(3.0));
+        assert_eq!(px(values.get("margin-left")), Some(8.0));
+        // Yazar stil sayfası, kullanıcı stil sayfasından sonra gelmese de onu yener
+        let values = computed_values_with_origins(html, &[(Origin::Author, "body { color: red }"), (Origin::User, "body { color: blue }")], "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+    }
+
+    #[test]
+    fn important_declarations_reverse_the_origin_order() {
+        let html = "<div id=\"t\"></div>";
+        let values = computed_values_with_origins(html, &[(Origin::User, "div { color: blue !important }"), (Origin::Author, "#t { color: red !important }")], "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+        let values = computed_values_with_origins(html, &[(Origin::User, "div { color: blue !important }"), (Origin::Author, "#t { color: red }")], "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+        // Aynı kökende önemli bildirim özgüllüğü ve kaynak sırasını yener
+        let values = computed_values(html, "div { color: blue !important } #t { color: red }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+    }
+
+    #[test]
+    fn layers_cascade_in_declaration_order_before_unlayered_rules() {
+        let html = "<div id=\"t\"></div>";
+        let values = computed_values(html, "@layer a, b; @layer b { div { color: blue } } @layer a { #t { color: red } }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+        // Katmansız kurallar katmanlardakileri özgüllükten bağımsız olarak yener
+        let values = computed_values(html, "div { color: lime } @layer a { #t { color: red } }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 255, 0, 1.0)));
+        // Önemli bildirimlerde katman sırası tersine döner
+        let values = computed_values(html, "@layer a { div { color: red !important } } @layer b { div { color: blue !important } }", "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+        let values = computed_values(html, "div { color: lime !important } @layer a { div { color: red !important } }", "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+    }
+
+    #[test]
+    fn relative_font_weights_resolve_against_the_parent() {
+        let weight = |parent: &str, keyword: &str| {
+            let css = format!("#p {{ font-weight: {} }} #t {{ font-weight: {} }}", parent, keyword);
+            match computed_values("<div id=\"p\"><p id=\"t\"></p></div>", &css, "t").get("font-weight") {
+                Some(Value::Number(weight)) => *weight,
+                value => panic!("unexpected font-weight {:?}", value),
+            }
+        };
+        assert_eq!(weight("100", "bolder"), 400.0);
+        assert_eq!(weight("normal", "bolder"), 700.0);
+        assert_eq!(weight("600", "bolder"), 900.0);
+        assert_eq!(weight("950", "bolder"), 950.0);
+        assert_eq!(weight("50", "lighter"), 50.0);
+        assert_eq!(weight("bold", "lighter"), 400.0);
+        assert_eq!(weight("500", "lighter"), 100.0);
+        assert_eq!(weight("900", "lighter"), 700.0);
+        // Ebeveynin göreli değeri önce kendi ebeveynine göre çözülür
+        assert_eq!(weight("bolder", "bolder"), 900.0);
+    }
+
+    #[test]
+    fn nested_and_anonymous_layers_cascade_in_declaration_order() {
+        let html = "<div id=\"t\"></div>";
+        // Katmana doğrudan yazılan kurallar alt katmanlarını yener
+        let values = computed_values(html, "@layer a { @layer x { #t { color: red } } div { color: blue } }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+        // Alt katmanlar üst katmanlarının sırasını paylaşır; sonradan bildirilen kardeş katman kazanır
+        let values = computed_values(html, "@layer a, b; @layer b.x { div { color: blue } } @layer a.y { #t { color: red } }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+        // Adsız katmanlar birbirinden ayrıdır ve bildirildikleri sırada yer alır
+        let values = computed_values(html, "@layer { div { color: lime } } @layer a { #t { color: red } } @layer { div { color: blue } }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+    }
+
+    #[test]
+    fn specificity_beats_source_order() {
+        let html = "<div id=\"t\" class=\"c\"></div>";
+        let values = computed_values(html, "#t { color: red } .c { color: blue } div { color: lime }", "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+        let values = computed_values(html, ".c { color: red } .c { color: blue }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+        // Seçici listesinde elemana uyan en özgül seçici sayılır
+        let values = computed_values(html, "div.c { color: red } p, #t { color: blue }", "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+    }
+
+    #[test]
+    fn revert_rolls_back_to_the_previous_origin() {
+        let html = "<html><body id=\"t\"></body></html>";
+        let values = computed_values_with_origins(html, &[(Origin::User, "body { color: blue }"), (Origin::Author, "body { color: red } #t { color: revert }")], "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 255, 1.0)));
+        // Kullanıcı değeri yoksa kullanıcı ajanı değerine döner
+        let values = computed_values(html, "body { margin: 20px } body { margin: revert }", "t");
+        assert_eq!(px(values.get("margin-top")), Some(8.0));
+        // Hiçbir köken değer vermiyorsa `unset` gibi davranır
+        let values = computed_values(html, "html { color: red } body { color: blue; color: revert }", "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+    }
+
+    #[test]
+    fn user_agent_stylesheet_sets_tag_defaults() {
+        let html = "<html><body><h1 id=\"h\"></h1><p id=\"p\"></p><span id=\"s\"></span></body></html>";
+        let h1 = computed_values(html, "", "h");
+        assert!(matches!(h1.get("display"), Some(Value::Display(Display::Block))));
+        assert_eq!(px(h1.get("font-size")), Some(32.0));
+        assert!(px(h1.get("margin-top")).is_some_and(|margin| (margin - 21.44).abs() < 0.01));
+        assert!(matches!(h1.get("font-weight"), Some(Value::Number(weight)) if *weight == 700.0));
+        assert_eq!(px(computed_values(html, "", "p").get("margin-bottom")), Some(16.0));
+        assert!(matches!(computed_values(html, "", "s").get("display"), Some(Value::Display(Display::Inline))));
+        // Yazar stil sayfası varsayılanları ezer
+        let h1 = computed_values(html, "h1 { margin-top: 0; display: inline }", "h");
+        assert_eq!(px(h1.get("margin-top")), Some(0.0));
+        assert!(matches!(h1.get("display"), Some(Value::Display(Display::Inline))));
+    }
+
+    #[test]
+    fn only_inherited_properties_flow_to_children() {
+        let html = "<div><p id=\"t\"></p></div>";
+        let parent = "div { color: red; background-color: blue; padding-top: 5px }";
+        let values = computed_values(html, parent, "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+        assert_ne!(srgb(values.get("background-color")), Some((0, 0, 255, 1.0)));
+        assert_ne!(px(values.get("padding-top")), Some(5.0));
+    }
+
+    #[test]
+    fn css_wide_keywords() {
+        let html = "<div><p id=\"t\"></p></div>";
+        let parent = "div { color: red; background-color: blue; padding-top: 5px } ";
+        let values = computed_values(html, &format!("{parent} p {{ background-color: inherit; padding-top: inherit }}"), "t");
+        assert_eq!(srgb(values.get("background-color")), Some((0, 0, 255, 1.0)));
+        assert_eq!(px(values.get("padding-top")), Some(5.0));
+        let values = computed_values(html, &format!("{parent} p {{ color: initial; background-color: initial }}"), "t");
+        assert_eq!(srgb(values.get("color")), Some((0, 0, 0, 1.0)));
+        assert_eq!(srgb(values.get("background-color")), Some((0, 0, 0, 0.0)));
+        // unset kalıtılan özelliklerde inherit, diğerlerinde initial gibidir
+        let values = computed_values(html, &format!("{parent} p {{ color: blue; padding-top: 9px }} p {{ color: unset; padding-top: unset }}"), "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+        assert_eq!(px(values.get("padding-top")), Some(0.0));
+    }
+
+    #[test]
+    fn relative_units_resolve_to_pixels() {
+        let html = "<html><body><div id=\"t\"><span id=\"s\"></span></div></body></html>";
+        let css = "html { font-size: 20px } div { font-size: 2em; width: 10em; margin-top: 1rem; height: 50vw; \
+                   padding-top: 10vh; padding-left: 5vmin; padding-right: 5vmax } span { font-size: 50% }";
+        let values = computed_values(html, css, "t");
+        // font-size içindeki em ebeveynin, diğer özelliklerdeki em elemanın kendi font-size'ına göredir
+        assert_eq!(px(values.get("font-size")), Some(40.0));
+        assert_eq!(px(values.get("width")), Some(400.0));
+        // rem kök elemanın font-size'ına göredir
+        assert_eq!(px(values.get("margin-top")), Some(20.0));
+        assert_eq!(px(values.get("height")), Some(400.0));
+        assert_eq!(px(values.get("padding-top")), Some(60.0));
+        assert_eq!(px(values.get("padding-left")), Some(30.0));
+        assert_eq!(px(values.get("padding-right")), Some(40.0));
+        assert_eq!(px(computed_values(html, css, "s").get("font-size")), Some(20.0));
+    }
+
+    #[test]
+    fn user_agent_stylesheet_has_only_valid_declarations() {
+        let mut parser = CssParser::new(include_str!("ua.css").to_string());
+        parser.parse();
+        let warnings: Vec<_> = parser.warnings.iter().map(|warning| &warning.message).collect();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn invalid_value_after_substitution_is_unset() {
+        let values = computed_values("<div><p id=\"t\"></p></div>", "div { color: red } p { --c: bogus; color: blue; color: var(--c) }", "t");
+        assert_eq!(srgb(values.get("color")), Some((255, 0, 0, 1.0)));
+    }
 }
@@ -0,0 +1,117 @@
+//! CSS custom properties module
+//!
+//! This module resolves custom properties (`--name`) and substitutes `var()` references
+//! at computed-value time.
+
+use std::collections::{HashMap, HashSet};
+use crate::css::enums::{Token, Value};
+use crate::css::parser::{split_top_level, trim_whitespace};
+
+/// Returns true for custom property names such as `--brand-color`
+pub fn is_custom_property(name: &str) -> bool {
+    name.starts_with("--")
+}
+
+/// Substitutes every `var()` reference in the tokens.
+/// Returns `None` when a reference cannot be resolved and has no fallback.
+pub fn substitute(tokens: &[Token], lookup: &mut dyn FnMut(&str) -> Option<Vec<Token>>) -> Option<Vec<Token>> {
+    let mut substituted = vec![];
+    let mut index = 0;
+    while index < tokens.len() {
+        match &tokens[index] {
+            Token::Function(name) if name.eq_ignore_ascii_case("var") => {
+                let end = closing_paren(tokens, index);
+                substituted.extend(substitute_var(&tokens[index + 1..end], lookup)?);
+                index = end + 1;
+            }
+            token => {
+                substituted.push(token.clone());
+                index += 1;
+            }
+        }
+    }
+    Some(substituted)
+}
+
+// Verilen konumdaki fonksiyonu kapatan parantezin konumu; kapanmamışsa token sayısı
+fn closing_paren(tokens: &[Token], start: usize) -> usize {
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::Function(_) | Token::OpenParen => depth += 1,
+            Token::CloseParen => {
+                depth -= 1;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len()
+}
+
+// `var(--ad, yedek)` argümanlarını çözer; değişken yoksa yedek değer kullanılır
+fn substitute_var(args: &[Token], lookup: &mut dyn FnMut(&str) -> Option<Vec<Token>>) -> Option<Vec<Token>> {
+    let name_tokens = split_top_level(args, &Token::Comma)[0];
+    let Token::Ident(name) = trim_whitespace(name_tokens).first()? else {
+        return None;
+    };
+    if !is_custom_property(name) || trim_whitespace(name_tokens).len() != 1 {
+        return None;
+    }
+    if let Some(value) = lookup(name) {
+        return Some(value);
+    }
+    // İlk virgülden sonraki her şey (virgüller dahil) yedek değerdir
+    let fallback = args.get(name_tokens.len() + 1..)?;
+    substitute(trim_whitespace(fallback), lookup)
+}
+
+/// Resolves `var()` references inside custom property values.
+/// Custom properties that take part in a reference cycle become invalid and are removed.
+pub fn resolve_custom_properties(values: &mut HashMap<String, Value>) {
+    let raw: HashMap<String, Vec<Token>> = values.iter()
+        .filter_map(|(name, value)| match value {
+            Value::Tokens(tokens) if is_custom_property(name) => Some((name.clone(), tokens.clone())),
+            _ => None,
+        })
+        .collect();
+    let mut resolver = Resolver { raw: &raw, stack: vec![], cyclic: HashSet::new(), resolved: HashMap::new() };
+    for name in raw.keys() {
+        match resolver.resolve(name) {
+            Some(tokens) => values.insert(name.clone(), Value::Tokens(tokens)),
+            None => values.remove(name),
+        };
+    }
+}
+
+// Özel özellikleri derinlik öncelikli çözer; yığında tekrar görülen özellik bir döngüye işaret eder
+struct Resolver<'a> {
+    raw: &'a HashMap<String, Vec<Token>>,
+    stack: Vec<String>,
+    cyclic: HashSet<String>,
+    resolved: HashMap<String, Option<Vec<Token>>>,
+}
+
+impl Resolver<'_> {
+    fn resolve(&mut self, name: &str) -> Option<Vec<Token>> {
+        if let Some(resolved) = self.resolved.get(name) {
+            return resolved.clone();
+        }
+        // Döngüdeki tüm özellikler geçersiz sayılır
+        if let Some(position) = self.stack.iter().position(|entry| entry == name) {
+            self.cyclic.extend(self.stack[position..].iter().cloned());
+            return None;
+        }
+        let raw = self.raw.get(name)?.clone();
+
+        self.stack.push(name.to_string());
+        let value = substitute(&raw, &mut |reference| self.resolve(reference));
+        self.stack.pop();
+
+        let value = if self.cyclic.contains(name) { None } else { value };
+        self.resolved.insert(name.to_string(), value.clone());
+        value
+    }
+}